@@ -0,0 +1,14 @@
+#![no_main]
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+};
+
+use libfuzzer_sys::fuzz_target;
+use sorted_insert_fuzz::{run, Input};
+
+fuzz_target!(|input: Input| {
+    run::<Vec<Arc<Mutex<u8>>>>(&input);
+    run::<VecDeque<Arc<Mutex<u8>>>>(&input);
+});
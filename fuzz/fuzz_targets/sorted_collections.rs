@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sorted_insert::{SortedBag, SortedChunkedVec, SortedVecMap};
+
+fuzz_target!(|input: (SortedVecMap<u8, u8>, SortedBag<u8>, SortedChunkedVec<u8>)| {
+    let (map, bag, chunked) = input;
+
+    assert!(map.keys().zip(map.keys().skip(1)).all(|(a, b)| a < b));
+
+    let elements: Vec<u8> = bag.iter().copied().collect();
+
+    assert_eq!(bag.len(), elements.len());
+    assert!(elements.windows(2).all(|w| w[0] <= w[1]));
+
+    let elements: Vec<u8> = chunked.iter().copied().collect();
+
+    assert_eq!(chunked.len(), elements.len());
+    assert!(elements.windows(2).all(|w| w[0] <= w[1]));
+});
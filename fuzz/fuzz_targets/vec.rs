@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use sorted_insert_fuzz::{run, Input};
+
+fuzz_target!(|input: Input| {
+    run::<Vec<u8>>(&input);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use std::collections::VecDeque;
+
+use libfuzzer_sys::fuzz_target;
+use sorted_insert_fuzz::{run, Input};
+
+fuzz_target!(|input: Input| {
+    run::<VecDeque<u8>>(&input);
+});
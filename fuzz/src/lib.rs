@@ -0,0 +1,331 @@
+//! Shared harness of the fuzz targets. Every target runs a sequence of operations on three copies of a sorted collection, which insert with linear, binary and gallop search respectively, and checks them against a reference model after each operation.
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use arbitrary::Arbitrary;
+use sorted_insert::*;
+
+/// An operation to be applied to the collections under test and the model.
+#[derive(Debug, Arbitrary)]
+pub enum Operation {
+    /// Insert an element.
+    Insert(u8),
+    /// Remove the element at the index, modulo the length.
+    Remove(u16),
+    /// Search for an element.
+    Search(u8),
+}
+
+/// The input of a fuzz target.
+#[derive(Debug, Arbitrary)]
+pub struct Input {
+    pub descending: bool,
+    pub operations: Vec<Operation>,
+}
+
+/// The reference model, which pushes elements and then sorts them with a stable sort.
+pub struct Model {
+    order:    Order,
+    elements: Vec<u8>,
+}
+
+impl Model {
+    #[inline]
+    pub fn new(order: Order) -> Self {
+        Model {
+            order,
+            elements: Vec::new(),
+        }
+    }
+
+    /// Return the range of the indices of the elements equal to `element`.
+    pub fn equal_range(&self, element: u8) -> (usize, usize) {
+        let start = self
+            .elements
+            .iter()
+            .filter(|e| self.order.compare(*e, &element) == std::cmp::Ordering::Less)
+            .count();
+        let end = start + self.elements.iter().filter(|e| **e == element).count();
+
+        (start, end)
+    }
+
+    /// Insert an element and return the range of indices, inclusive on both ends, where a sorted insertion may put it.
+    pub fn insert(&mut self, element: u8) -> (usize, usize) {
+        let range = self.equal_range(element);
+
+        self.elements.push(element);
+
+        let order = self.order;
+
+        self.elements.sort_by(|a, b| order.compare(a, b));
+
+        range
+    }
+
+    #[inline]
+    pub fn remove(&mut self, index: usize) -> u8 {
+        self.elements.remove(index)
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.elements
+    }
+}
+
+/// A sorted collection under test.
+pub trait Subject: Default {
+    fn insert_linear(&mut self, element: u8, order: Order) -> usize;
+
+    fn insert_binary(&mut self, element: u8, order: Order) -> usize;
+
+    fn insert_gallop(&mut self, element: u8, order: Order) -> usize;
+
+    fn remove(&mut self, index: usize) -> u8;
+
+    /// Search for an element with binary search, or return `None` if the collection does not support searching.
+    fn search(&self, element: u8, order: Order) -> Option<Result<usize, usize>>;
+
+    fn to_vec(&self) -> Vec<u8>;
+}
+
+/// Run the operations and panic at the first divergence from the model.
+pub fn run<S: Subject>(input: &Input) {
+    let order = if input.descending { Order::Descending } else { Order::Ascending };
+
+    let mut model = Model::new(order);
+    let mut linear = S::default();
+    let mut binary = S::default();
+    let mut gallop = S::default();
+
+    for operation in input.operations.iter() {
+        match *operation {
+            Operation::Insert(element) => {
+                let (start, end) = model.insert(element);
+
+                // linear and gallop search put the element after the equal ones, binary search may put it anywhere among them
+                assert_eq!(
+                    end,
+                    linear.insert_linear(element, order),
+                    "linear insert of {}",
+                    element
+                );
+                assert_eq!(
+                    end,
+                    gallop.insert_gallop(element, order),
+                    "gallop insert of {}",
+                    element
+                );
+
+                let index = binary.insert_binary(element, order);
+
+                assert!(
+                    (start..=end).contains(&index),
+                    "binary insert of {} at {}, expected {}..={}",
+                    element,
+                    index,
+                    start,
+                    end
+                );
+            },
+            Operation::Remove(index) => {
+                if model.is_empty() {
+                    continue;
+                }
+
+                let index = index as usize % model.len();
+                let element = model.remove(index);
+
+                assert_eq!(element, linear.remove(index));
+                assert_eq!(element, binary.remove(index));
+                assert_eq!(element, gallop.remove(index));
+            },
+            Operation::Search(element) => {
+                let (start, end) = model.equal_range(element);
+
+                match binary.search(element, order) {
+                    Some(Ok(index)) => assert!(
+                        (start..end).contains(&index),
+                        "found {} at {}, expected {}..{}",
+                        element,
+                        index,
+                        start,
+                        end
+                    ),
+                    Some(Err(index)) => {
+                        assert_eq!(start, end, "{} is not found", element);
+                        assert_eq!(start, index);
+                    },
+                    None => (),
+                }
+            },
+        }
+
+        assert_eq!(model.as_slice(), linear.to_vec().as_slice());
+        assert_eq!(model.as_slice(), binary.to_vec().as_slice());
+        assert_eq!(model.as_slice(), gallop.to_vec().as_slice());
+    }
+}
+
+macro_rules! impl_subject {
+    (
+        $t:ty, |
+        $v:ident,
+        $index:ident |
+        $remove:expr, |
+        $s:ident,
+        $element:ident,
+        $order:ident |
+        $search:expr
+    ) => {
+        impl Subject for $t {
+            #[inline]
+            fn insert_linear(&mut self, element: u8, order: Order) -> usize {
+                SortedInsert::sorted_insert_ordered(self, element, order)
+            }
+
+            #[inline]
+            fn insert_binary(&mut self, element: u8, order: Order) -> usize {
+                SortedInsertBinary::sorted_insert_binary_ordered(self, element, order)
+            }
+
+            #[inline]
+            fn insert_gallop(&mut self, element: u8, order: Order) -> usize {
+                SortedInsertGallop::sorted_insert_gallop_ordered(self, element, order)
+            }
+
+            #[inline]
+            fn remove(&mut self, $index: usize) -> u8 {
+                let $v = self;
+
+                $remove
+            }
+
+            #[inline]
+            fn search(&self, $element: u8, $order: Order) -> Option<Result<usize, usize>> {
+                let $s = self;
+
+                $search
+            }
+
+            #[inline]
+            fn to_vec(&self) -> Vec<u8> {
+                self.iter().copied().collect()
+            }
+        }
+    };
+}
+
+impl_subject!(Vec<u8>, |v, index| v.remove(index), |s, element, order| Some(
+    s.sorted_search_by_comparator(&element, &order)
+));
+
+impl_subject!(VecDeque<u8>, |v, index| v.remove(index).unwrap(), |s, element, order| Some(
+    s.sorted_search_by_comparator(&element, &order)
+));
+
+macro_rules! impl_lock_subject {
+    (
+        $t:ty,
+        $lock:ident,
+        $read:ident,
+        $linear:ident,
+        $binary:ident,
+        $gallop:ident, |
+        $v:ident,
+        $index:ident |
+        $remove:expr
+    ) => {
+        impl Subject for $t {
+            #[inline]
+            fn insert_linear(&mut self, element: u8, order: Order) -> usize {
+                $linear::sorted_insert_ordered(self, Arc::new($lock::new(element)), order)
+            }
+
+            #[inline]
+            fn insert_binary(&mut self, element: u8, order: Order) -> usize {
+                $binary::sorted_insert_binary_ordered(self, Arc::new($lock::new(element)), order)
+            }
+
+            #[inline]
+            fn insert_gallop(&mut self, element: u8, order: Order) -> usize {
+                $gallop::sorted_insert_gallop_ordered(self, Arc::new($lock::new(element)), order)
+            }
+
+            #[inline]
+            fn remove(&mut self, $index: usize) -> u8 {
+                let $v = self;
+
+                let element = $remove;
+
+                let value = *element.$read().unwrap();
+
+                value
+            }
+
+            #[inline]
+            fn search(&self, _element: u8, _order: Order) -> Option<Result<usize, usize>> {
+                None
+            }
+
+            #[inline]
+            fn to_vec(&self) -> Vec<u8> {
+                self.iter().map(|e| *e.$read().unwrap()).collect()
+            }
+        }
+    };
+}
+
+impl_lock_subject!(
+    Vec<Arc<Mutex<u8>>>,
+    Mutex,
+    lock,
+    SortedInsertArcMutex,
+    SortedInsertBinaryArcMutex,
+    SortedInsertGallopArcMutex,
+    |v, index| v.remove(index)
+);
+
+impl_lock_subject!(
+    VecDeque<Arc<Mutex<u8>>>,
+    Mutex,
+    lock,
+    SortedInsertArcMutex,
+    SortedInsertBinaryArcMutex,
+    SortedInsertGallopArcMutex,
+    |v, index| v.remove(index).unwrap()
+);
+
+impl_lock_subject!(
+    Vec<Arc<RwLock<u8>>>,
+    RwLock,
+    read,
+    SortedInsertArcRwLock,
+    SortedInsertBinaryArcRwLock,
+    SortedInsertGallopArcRwLock,
+    |v, index| v.remove(index)
+);
+
+impl_lock_subject!(
+    VecDeque<Arc<RwLock<u8>>>,
+    RwLock,
+    read,
+    SortedInsertArcRwLock,
+    SortedInsertBinaryArcRwLock,
+    SortedInsertGallopArcRwLock,
+    |v, index| v.remove(index).unwrap()
+);
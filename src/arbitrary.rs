@@ -0,0 +1,64 @@
+use alloc::vec::Vec;
+
+use ::arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use crate::{SortedBag, SortedChunkedVec, SortedVecMap};
+
+impl<'a, K: Arbitrary<'a> + Ord, V: Arbitrary<'a>> Arbitrary<'a> for SortedVecMap<K, V> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    #[inline]
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(<usize as Arbitrary>::size_hint(depth), (0, None))
+    }
+}
+
+impl<'a, T: Arbitrary<'a> + Ord> Arbitrary<'a> for SortedBag<T> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    #[inline]
+    fn arbitrary_take_rest(u: Unstructured<'a>) -> Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(<usize as Arbitrary>::size_hint(depth), (0, None))
+    }
+}
+
+impl<'a, T: Arbitrary<'a> + Ord> Arbitrary<'a> for SortedChunkedVec<T> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        // small chunks, so that even a few elements are spread over several chunks
+        let chunk_size = u.int_in_range(2..=8)?;
+
+        Ok(SortedChunkedVec::from_vec_with_chunk_size(Vec::<T>::arbitrary(u)?, chunk_size))
+    }
+
+    #[inline]
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> Result<Self> {
+        let chunk_size = u.int_in_range(2..=8)?;
+
+        Ok(SortedChunkedVec::from_vec_with_chunk_size(
+            Vec::<T>::arbitrary_take_rest(u)?,
+            chunk_size,
+        ))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(<u8 as Arbitrary>::size_hint(depth), Vec::<T>::size_hint(depth))
+    }
+}
@@ -3,9 +3,10 @@ use core::cmp::Ordering;
 use std::sync::{Arc, Mutex};
 
 use crate::{
-    SortedInsertArcMutex, SortedInsertArcMutexBasic, SortedInsertArcMutexBy,
+    search, SortedInsertArcMutex, SortedInsertArcMutexBasic, SortedInsertArcMutexBy,
     SortedInsertArcMutexByKey, SortedInsertBinaryArcMutex, SortedInsertBinaryArcMutexBy,
-    SortedInsertBinaryArcMutexByKey,
+    SortedInsertBinaryArcMutexByKey, SortedInsertGallopArcMutex, SortedInsertGallopArcMutexBy,
+    SortedInsertGallopArcMutexByKey,
 };
 
 impl<T> SortedInsertArcMutexBasic<T> for Vec<Arc<Mutex<T>>> {
@@ -45,3 +46,17 @@ impl<T> SortedInsertBinaryArcMutexBy<T> for Vec<Arc<Mutex<T>>> {
 impl<T> SortedInsertBinaryArcMutexByKey<T> for Vec<Arc<Mutex<T>>> {}
 
 impl<T: Ord> SortedInsertBinaryArcMutex<T> for Vec<Arc<Mutex<T>>> {}
+
+impl<T> SortedInsertGallopArcMutexBy<T> for Vec<Arc<Mutex<T>>> {
+    #[inline]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&Arc<Mutex<T>>) -> Ordering>(
+        &mut self,
+        f: F,
+    ) -> usize {
+        search::gallop_by(self, self.len(), f)
+    }
+}
+
+impl<T> SortedInsertGallopArcMutexByKey<T> for Vec<Arc<Mutex<T>>> {}
+
+impl<T: Ord> SortedInsertGallopArcMutex<T> for Vec<Arc<Mutex<T>>> {}
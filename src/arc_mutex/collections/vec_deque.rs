@@ -5,9 +5,10 @@ use std::{
 };
 
 use crate::{
-    SortedInsertArcMutex, SortedInsertArcMutexBasic, SortedInsertArcMutexBy,
+    search, SortedInsertArcMutex, SortedInsertArcMutexBasic, SortedInsertArcMutexBy,
     SortedInsertArcMutexByKey, SortedInsertBinaryArcMutex, SortedInsertBinaryArcMutexBy,
-    SortedInsertBinaryArcMutexByKey,
+    SortedInsertBinaryArcMutexByKey, SortedInsertGallopArcMutex, SortedInsertGallopArcMutexBy,
+    SortedInsertGallopArcMutexByKey,
 };
 
 impl<T> SortedInsertArcMutexBasic<T> for VecDeque<Arc<Mutex<T>>> {
@@ -47,3 +48,19 @@ impl<T> SortedInsertBinaryArcMutexBy<T> for VecDeque<Arc<Mutex<T>>> {
 impl<T> SortedInsertBinaryArcMutexByKey<T> for VecDeque<Arc<Mutex<T>>> {}
 
 impl<T: Ord> SortedInsertBinaryArcMutex<T> for VecDeque<Arc<Mutex<T>>> {}
+
+impl<T> SortedInsertGallopArcMutexBy<T> for VecDeque<Arc<Mutex<T>>> {
+    #[inline]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&Arc<Mutex<T>>) -> Ordering>(
+        &mut self,
+        f: F,
+    ) -> usize {
+        let len = self.len();
+
+        search::gallop_by(self.make_contiguous(), len, f)
+    }
+}
+
+impl<T> SortedInsertGallopArcMutexByKey<T> for VecDeque<Arc<Mutex<T>>> {}
+
+impl<T: Ord> SortedInsertGallopArcMutex<T> for VecDeque<Arc<Mutex<T>>> {}
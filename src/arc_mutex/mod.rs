@@ -3,6 +3,8 @@ mod collections;
 use core::cmp::Ordering;
 use std::sync::{Arc, Mutex};
 
+use crate::Order;
+
 #[doc(hidden)]
 pub trait SortedInsertArcMutexBasic<T> {
     #[doc(hidden)]
@@ -82,6 +84,24 @@ pub trait SortedInsertArcMutexByKey<T>: SortedInsertArcMutexBy<T> {
             }
         })
     }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<Mutex<T>>,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_desc_by_key(element, f),
+        }
+    }
 }
 
 pub trait SortedInsertArcMutex<T: Ord>: SortedInsertArcMutexByKey<T> {
@@ -104,6 +124,19 @@ pub trait SortedInsertArcMutex<T: Ord>: SortedInsertArcMutexByKey<T> {
     fn sorted_insert_desc(&mut self, element: Arc<Mutex<T>>) -> usize {
         self.sorted_insert_desc_by_key(element, |element| element)
     }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_ordered(&mut self, element: Arc<Mutex<T>>, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc(element),
+            Order::Descending => self.sorted_insert_desc(element),
+        }
+    }
 }
 
 pub trait SortedInsertBinaryArcMutexBy<T>: SortedInsertArcMutexBy<T> {
@@ -181,6 +214,24 @@ pub trait SortedInsertBinaryArcMutexByKey<T>: SortedInsertBinaryArcMutexBy<T> {
             }
         })
     }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_binary_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<Mutex<T>>,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_binary_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_binary_desc_by_key(element, f),
+        }
+    }
 }
 
 pub trait SortedInsertBinaryArcMutex<T: Ord>: SortedInsertBinaryArcMutexByKey<T> {
@@ -203,4 +254,147 @@ pub trait SortedInsertBinaryArcMutex<T: Ord>: SortedInsertBinaryArcMutexByKey<T>
     fn sorted_insert_desc_binary(&mut self, element: Arc<Mutex<T>>) -> usize {
         self.sorted_insert_binary_desc_by_key(element, |element| element)
     }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_binary_ordered(&mut self, element: Arc<Mutex<T>>, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_binary(element),
+            Order::Descending => self.sorted_insert_desc_binary(element),
+        }
+    }
+}
+
+pub trait SortedInsertGallopArcMutexBy<T>: SortedInsertArcMutexBy<T> {
+    /// Insert elements to this sorted collection by a specific comparator and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    fn sorted_insert_gallop_by<F: FnMut(&Arc<Mutex<T>>, &T) -> Ordering>(
+        &mut self,
+        element: Arc<Mutex<T>>,
+        mut f: F,
+    ) -> usize {
+        let element_guard = element.lock().unwrap();
+
+        let index = self.get_sorted_insert_index_gallop_by(|e| f(e, &*element_guard));
+
+        drop(element_guard);
+
+        self.insert_element(index, element);
+
+        index
+    }
+
+    #[doc(hidden)]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&Arc<Mutex<T>>) -> Ordering>(
+        &mut self,
+        f: F,
+    ) -> usize;
+}
+
+pub trait SortedInsertGallopArcMutexByKey<T>: SortedInsertGallopArcMutexBy<T> {
+    /// Insert elements to this sorted collection in ascending order by a specific key and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_gallop_asc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<Mutex<T>>,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_gallop_by(element.clone(), |e, element_t| {
+            // if the element is the same as the one being inserted, we can skip the comparison, in order to avoid deadlocks
+            if Arc::ptr_eq(e, &element) {
+                Ordering::Equal
+            } else {
+                let e_guard = e.lock().unwrap();
+
+                f(&*e_guard).cmp(f(element_t))
+            }
+        })
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_gallop_desc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<Mutex<T>>,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_gallop_by(element.clone(), |e, element_t| {
+            // if the element is the same as the one being inserted, we can skip the comparison, in order to avoid deadlocks
+            if Arc::ptr_eq(e, &element) {
+                Ordering::Equal
+            } else {
+                let e_guard = e.lock().unwrap();
+
+                f(element_t).cmp(f(&*e_guard))
+            }
+        })
+    }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_gallop_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<Mutex<T>>,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_gallop_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_gallop_desc_by_key(element, f),
+        }
+    }
+}
+
+pub trait SortedInsertGallopArcMutex<T: Ord>: SortedInsertGallopArcMutexByKey<T> {
+    /// Insert elements to this sorted collection in ascending order and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_asc_gallop(&mut self, element: Arc<Mutex<T>>) -> usize {
+        self.sorted_insert_gallop_asc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_desc_gallop(&mut self, element: Arc<Mutex<T>>) -> usize {
+        self.sorted_insert_gallop_desc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_gallop_ordered(&mut self, element: Arc<Mutex<T>>, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_gallop(element),
+            Order::Descending => self.sorted_insert_desc_gallop(element),
+        }
+    }
 }
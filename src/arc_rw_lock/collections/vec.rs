@@ -3,9 +3,10 @@ use core::cmp::Ordering;
 use std::sync::{Arc, RwLock};
 
 use crate::{
-    SortedInsertArcRwLock, SortedInsertArcRwLockBasic, SortedInsertArcRwLockBy,
+    search, SortedInsertArcRwLock, SortedInsertArcRwLockBasic, SortedInsertArcRwLockBy,
     SortedInsertArcRwLockByKey, SortedInsertBinaryArcRwLock, SortedInsertBinaryArcRwLockBy,
-    SortedInsertBinaryArcRwLockByKey,
+    SortedInsertBinaryArcRwLockByKey, SortedInsertGallopArcRwLock, SortedInsertGallopArcRwLockBy,
+    SortedInsertGallopArcRwLockByKey,
 };
 
 impl<T> SortedInsertArcRwLockBasic<T> for Vec<Arc<RwLock<T>>> {
@@ -45,3 +46,17 @@ impl<T> SortedInsertBinaryArcRwLockBy<T> for Vec<Arc<RwLock<T>>> {
 impl<T> SortedInsertBinaryArcRwLockByKey<T> for Vec<Arc<RwLock<T>>> {}
 
 impl<T: Ord> SortedInsertBinaryArcRwLock<T> for Vec<Arc<RwLock<T>>> {}
+
+impl<T> SortedInsertGallopArcRwLockBy<T> for Vec<Arc<RwLock<T>>> {
+    #[inline]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&Arc<RwLock<T>>) -> Ordering>(
+        &mut self,
+        f: F,
+    ) -> usize {
+        search::gallop_by(self, self.len(), f)
+    }
+}
+
+impl<T> SortedInsertGallopArcRwLockByKey<T> for Vec<Arc<RwLock<T>>> {}
+
+impl<T: Ord> SortedInsertGallopArcRwLock<T> for Vec<Arc<RwLock<T>>> {}
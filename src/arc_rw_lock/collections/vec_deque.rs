@@ -5,9 +5,10 @@ use std::{
 };
 
 use crate::{
-    SortedInsertArcRwLock, SortedInsertArcRwLockBasic, SortedInsertArcRwLockBy,
+    search, SortedInsertArcRwLock, SortedInsertArcRwLockBasic, SortedInsertArcRwLockBy,
     SortedInsertArcRwLockByKey, SortedInsertBinaryArcRwLock, SortedInsertBinaryArcRwLockBy,
-    SortedInsertBinaryArcRwLockByKey,
+    SortedInsertBinaryArcRwLockByKey, SortedInsertGallopArcRwLock, SortedInsertGallopArcRwLockBy,
+    SortedInsertGallopArcRwLockByKey,
 };
 
 impl<T> SortedInsertArcRwLockBasic<T> for VecDeque<Arc<RwLock<T>>> {
@@ -47,3 +48,19 @@ impl<T> SortedInsertBinaryArcRwLockBy<T> for VecDeque<Arc<RwLock<T>>> {
 impl<T> SortedInsertBinaryArcRwLockByKey<T> for VecDeque<Arc<RwLock<T>>> {}
 
 impl<T: Ord> SortedInsertBinaryArcRwLock<T> for VecDeque<Arc<RwLock<T>>> {}
+
+impl<T> SortedInsertGallopArcRwLockBy<T> for VecDeque<Arc<RwLock<T>>> {
+    #[inline]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&Arc<RwLock<T>>) -> Ordering>(
+        &mut self,
+        f: F,
+    ) -> usize {
+        let len = self.len();
+
+        search::gallop_by(self.make_contiguous(), len, f)
+    }
+}
+
+impl<T> SortedInsertGallopArcRwLockByKey<T> for VecDeque<Arc<RwLock<T>>> {}
+
+impl<T: Ord> SortedInsertGallopArcRwLock<T> for VecDeque<Arc<RwLock<T>>> {}
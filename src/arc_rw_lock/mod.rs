@@ -3,6 +3,8 @@ mod collections;
 use core::cmp::Ordering;
 use std::sync::{Arc, RwLock};
 
+use crate::Order;
+
 #[doc(hidden)]
 pub trait SortedInsertArcRwLockBasic<T> {
     #[doc(hidden)]
@@ -72,6 +74,24 @@ pub trait SortedInsertArcRwLockByKey<T>: SortedInsertArcRwLockBy<T> {
             f(&*e_guard) >= f(element_t)
         })
     }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<RwLock<T>>,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_desc_by_key(element, f),
+        }
+    }
 }
 
 pub trait SortedInsertArcRwLock<T: Ord>: SortedInsertArcRwLockByKey<T> {
@@ -94,6 +114,19 @@ pub trait SortedInsertArcRwLock<T: Ord>: SortedInsertArcRwLockByKey<T> {
     fn sorted_insert_desc(&mut self, element: Arc<RwLock<T>>) -> usize {
         self.sorted_insert_desc_by_key(element, |element| element)
     }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_ordered(&mut self, element: Arc<RwLock<T>>, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc(element),
+            Order::Descending => self.sorted_insert_desc(element),
+        }
+    }
 }
 
 pub trait SortedInsertBinaryArcRwLockBy<T>: SortedInsertArcRwLockBy<T> {
@@ -161,6 +194,24 @@ pub trait SortedInsertBinaryArcRwLockByKey<T>: SortedInsertBinaryArcRwLockBy<T>
             f(element_t).cmp(f(&*e_guard))
         })
     }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_binary_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<RwLock<T>>,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_binary_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_binary_desc_by_key(element, f),
+        }
+    }
 }
 
 pub trait SortedInsertBinaryArcRwLock<T: Ord>: SortedInsertBinaryArcRwLockByKey<T> {
@@ -183,4 +234,137 @@ pub trait SortedInsertBinaryArcRwLock<T: Ord>: SortedInsertBinaryArcRwLockByKey<
     fn sorted_insert_desc_binary(&mut self, element: Arc<RwLock<T>>) -> usize {
         self.sorted_insert_binary_desc_by_key(element, |element| element)
     }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_binary_ordered(&mut self, element: Arc<RwLock<T>>, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_binary(element),
+            Order::Descending => self.sorted_insert_desc_binary(element),
+        }
+    }
+}
+
+pub trait SortedInsertGallopArcRwLockBy<T>: SortedInsertArcRwLockBy<T> {
+    /// Insert elements to this sorted collection by a specific comparator and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    fn sorted_insert_gallop_by<F: FnMut(&Arc<RwLock<T>>, &T) -> Ordering>(
+        &mut self,
+        element: Arc<RwLock<T>>,
+        mut f: F,
+    ) -> usize {
+        let element_guard = element.read().unwrap();
+
+        let index = self.get_sorted_insert_index_gallop_by(|e| f(e, &*element_guard));
+
+        drop(element_guard);
+
+        self.insert_element(index, element);
+
+        index
+    }
+
+    #[doc(hidden)]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&Arc<RwLock<T>>) -> Ordering>(
+        &mut self,
+        f: F,
+    ) -> usize;
+}
+
+pub trait SortedInsertGallopArcRwLockByKey<T>: SortedInsertGallopArcRwLockBy<T> {
+    /// Insert elements to this sorted collection in ascending order by a specific key and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_gallop_asc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<RwLock<T>>,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_gallop_by(element.clone(), |e, element_t| {
+            let e_guard = e.read().unwrap();
+
+            f(&*e_guard).cmp(f(element_t))
+        })
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_gallop_desc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<RwLock<T>>,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_gallop_by(element.clone(), |e, element_t| {
+            let e_guard = e.read().unwrap();
+
+            f(element_t).cmp(f(&*e_guard))
+        })
+    }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_gallop_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: Arc<RwLock<T>>,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_gallop_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_gallop_desc_by_key(element, f),
+        }
+    }
+}
+
+pub trait SortedInsertGallopArcRwLock<T: Ord>: SortedInsertGallopArcRwLockByKey<T> {
+    /// Insert elements to this sorted collection in ascending order and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_asc_gallop(&mut self, element: Arc<RwLock<T>>) -> usize {
+        self.sorted_insert_gallop_asc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_desc_gallop(&mut self, element: Arc<RwLock<T>>) -> usize {
+        self.sorted_insert_gallop_desc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted.
+    ///
+    /// ## Safety
+    ///
+    /// This function will panic if the element is locked.
+    #[inline]
+    fn sorted_insert_gallop_ordered(&mut self, element: Arc<RwLock<T>>, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_gallop(element),
+            Order::Descending => self.sorted_insert_desc_gallop(element),
+        }
+    }
 }
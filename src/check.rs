@@ -0,0 +1,185 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[cfg(feature = "debug-checks")]
+use crate::SortedInsertBasic;
+
+/// The error returned when a collection is not sorted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct NotSortedError {
+    index: usize,
+}
+
+impl NotSortedError {
+    /// Return the index of the first element which is ordered after its next element.
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl Display for NotSortedError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the element at index {} is ordered after the element at index {}",
+            self.index,
+            self.index + 1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for NotSortedError {}
+
+/// Check whether the elements are sorted by a specific comparator.
+pub fn check_sorted_by<'a, T: 'a, I: IntoIterator<Item = &'a T>, F: FnMut(&T, &T) -> Ordering>(
+    elements: I,
+    mut f: F,
+) -> Result<(), NotSortedError> {
+    let mut iter = elements.into_iter();
+
+    let mut previous = match iter.next() {
+        Some(e) => e,
+        None => return Ok(()),
+    };
+
+    for (index, e) in iter.enumerate() {
+        if f(previous, e) == Ordering::Greater {
+            return Err(NotSortedError {
+                index,
+            });
+        }
+
+        previous = e;
+    }
+
+    Ok(())
+}
+
+/// Check whether the elements are sorted in ascending order by a specific key.
+#[inline]
+pub fn check_sorted_asc_by_key<
+    'a,
+    T: 'a,
+    I: IntoIterator<Item = &'a T>,
+    A: Ord,
+    F: FnMut(&T) -> &A,
+>(
+    elements: I,
+    mut f: F,
+) -> Result<(), NotSortedError> {
+    check_sorted_by(elements, |a, b| f(a).cmp(f(b)))
+}
+
+/// Check whether the elements are sorted in descending order by a specific key.
+#[inline]
+pub fn check_sorted_desc_by_key<
+    'a,
+    T: 'a,
+    I: IntoIterator<Item = &'a T>,
+    A: Ord,
+    F: FnMut(&T) -> &A,
+>(
+    elements: I,
+    mut f: F,
+) -> Result<(), NotSortedError> {
+    check_sorted_by(elements, |a, b| f(b).cmp(f(a)))
+}
+
+/// Check whether the elements are sorted in ascending order.
+#[inline]
+pub fn check_sorted_asc<'a, T: Ord + 'a, I: IntoIterator<Item = &'a T>>(
+    elements: I,
+) -> Result<(), NotSortedError> {
+    check_sorted_asc_by_key(elements, |e| e)
+}
+
+/// Check whether the elements are sorted in descending order.
+#[inline]
+pub fn check_sorted_desc<'a, T: Ord + 'a, I: IntoIterator<Item = &'a T>>(
+    elements: I,
+) -> Result<(), NotSortedError> {
+    check_sorted_desc_by_key(elements, |e| e)
+}
+
+/// Return `true` if the elements are sorted by a specific comparator.
+#[inline]
+pub fn is_sorted_by<'a, T: 'a, I: IntoIterator<Item = &'a T>, F: FnMut(&T, &T) -> Ordering>(
+    elements: I,
+    f: F,
+) -> bool {
+    check_sorted_by(elements, f).is_ok()
+}
+
+/// Return `true` if the elements are sorted in ascending order by a specific key.
+#[inline]
+pub fn is_sorted_asc_by_key<
+    'a,
+    T: 'a,
+    I: IntoIterator<Item = &'a T>,
+    A: Ord,
+    F: FnMut(&T) -> &A,
+>(
+    elements: I,
+    f: F,
+) -> bool {
+    check_sorted_asc_by_key(elements, f).is_ok()
+}
+
+/// Return `true` if the elements are sorted in descending order by a specific key.
+#[inline]
+pub fn is_sorted_desc_by_key<
+    'a,
+    T: 'a,
+    I: IntoIterator<Item = &'a T>,
+    A: Ord,
+    F: FnMut(&T) -> &A,
+>(
+    elements: I,
+    f: F,
+) -> bool {
+    check_sorted_desc_by_key(elements, f).is_ok()
+}
+
+/// Return `true` if the elements are sorted in ascending order.
+#[inline]
+pub fn is_sorted_asc<'a, T: Ord + 'a, I: IntoIterator<Item = &'a T>>(elements: I) -> bool {
+    check_sorted_asc(elements).is_ok()
+}
+
+/// Return `true` if the elements are sorted in descending order.
+#[inline]
+pub fn is_sorted_desc<'a, T: Ord + 'a, I: IntoIterator<Item = &'a T>>(elements: I) -> bool {
+    check_sorted_desc(elements).is_ok()
+}
+
+/// Panic if the elements around `index`, where an element has just been inserted, are not in order. `in_order` returns whether its first argument can be placed before its second argument.
+#[cfg(feature = "debug-checks")]
+pub(crate) fn check_inserted<T, C: SortedInsertBasic<T> + ?Sized, F: FnMut(&T, &T) -> bool>(
+    collection: &C,
+    index: usize,
+    mut in_order: F,
+) {
+    for i in index.saturating_sub(2)..index + 2 {
+        let (a, b) = match (collection.get_element(i), collection.get_element(i + 1)) {
+            (Some(a), Some(b)) => (a, b),
+            _ => break,
+        };
+
+        if !in_order(a, b) {
+            panic!(
+                "the collection is not sorted: the element at index {} is ordered after the \
+                 element at index {} (found after inserting an element at index {})",
+                i,
+                i + 1,
+                index
+            );
+        }
+    }
+}
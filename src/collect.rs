@@ -0,0 +1,103 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Collect the elements of iterators into sorted `Vec`s.
+///
+/// The elements are sorted in bulk with a stable sort, and the results are the same as inserting the elements one by one with the linear `SortedInsert*` methods, in which equal elements keep the order they are yielded.
+pub trait SortedCollect: Iterator + Sized {
+    /// Collect the elements into a `Vec` sorted by a specific comparator.
+    #[inline]
+    fn collect_sorted_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(
+        self,
+        f: F,
+    ) -> Vec<Self::Item> {
+        let mut v: Vec<Self::Item> = self.collect();
+
+        v.sort_by(f);
+
+        v
+    }
+
+    /// Collect the elements into a `Vec` sorted in ascending order by a specific key.
+    #[inline]
+    fn collect_sorted_asc_by_key<A: Ord, F: FnMut(&Self::Item) -> &A>(
+        self,
+        mut f: F,
+    ) -> Vec<Self::Item> {
+        self.collect_sorted_by(|a, b| f(a).cmp(f(b)))
+    }
+
+    /// Collect the elements into a `Vec` sorted in descending order by a specific key.
+    #[inline]
+    fn collect_sorted_desc_by_key<A: Ord, F: FnMut(&Self::Item) -> &A>(
+        self,
+        mut f: F,
+    ) -> Vec<Self::Item> {
+        self.collect_sorted_by(|a, b| f(b).cmp(f(a)))
+    }
+
+    /// Collect the elements into a `Vec` sorted in ascending order.
+    #[inline]
+    fn collect_sorted_asc(self) -> Vec<Self::Item>
+    where
+        Self::Item: Ord, {
+        self.collect_sorted_asc_by_key(|e| e)
+    }
+
+    /// Collect the elements into a `Vec` sorted in descending order.
+    #[inline]
+    fn collect_sorted_desc(self) -> Vec<Self::Item>
+    where
+        Self::Item: Ord, {
+        self.collect_sorted_desc_by_key(|e| e)
+    }
+
+    /// Collect the elements into a `Vec` sorted by a specific comparator, keeping only the first one of equal elements.
+    #[inline]
+    fn collect_sorted_unique_by<F: FnMut(&Self::Item, &Self::Item) -> Ordering>(
+        self,
+        mut f: F,
+    ) -> Vec<Self::Item> {
+        let mut v = self.collect_sorted_by(&mut f);
+
+        v.dedup_by(|a, b| f(a, b) == Ordering::Equal);
+
+        v
+    }
+
+    /// Collect the elements into a `Vec` sorted in ascending order by a specific key, keeping only the first one of the elements with equal keys.
+    #[inline]
+    fn collect_sorted_unique_asc_by_key<A: Ord, F: FnMut(&Self::Item) -> &A>(
+        self,
+        mut f: F,
+    ) -> Vec<Self::Item> {
+        self.collect_sorted_unique_by(|a, b| f(a).cmp(f(b)))
+    }
+
+    /// Collect the elements into a `Vec` sorted in descending order by a specific key, keeping only the first one of the elements with equal keys.
+    #[inline]
+    fn collect_sorted_unique_desc_by_key<A: Ord, F: FnMut(&Self::Item) -> &A>(
+        self,
+        mut f: F,
+    ) -> Vec<Self::Item> {
+        self.collect_sorted_unique_by(|a, b| f(b).cmp(f(a)))
+    }
+
+    /// Collect the elements into a `Vec` sorted in ascending order, keeping only the first one of equal elements.
+    #[inline]
+    fn collect_sorted_unique_asc(self) -> Vec<Self::Item>
+    where
+        Self::Item: Ord, {
+        self.collect_sorted_unique_asc_by_key(|e| e)
+    }
+
+    /// Collect the elements into a `Vec` sorted in descending order, keeping only the first one of equal elements.
+    #[inline]
+    fn collect_sorted_unique_desc(self) -> Vec<Self::Item>
+    where
+        Self::Item: Ord, {
+        self.collect_sorted_unique_desc_by_key(|e| e)
+    }
+}
+
+impl<I: Iterator> SortedCollect for I {}
@@ -1,3 +1,4 @@
+mod sorted_chunked_vec;
 mod vec;
 
 #[cfg(feature = "std")]
@@ -0,0 +1,193 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::{
+    merge, DedupKeep, SortedBulk, SortedBulkBy, SortedBulkByKey, SortedChunkedVec, SortedEntryBy,
+    SortedEntryByKey, SortedInsert, SortedInsertBasic, SortedInsertBinary, SortedInsertBinaryBy,
+    SortedInsertBinaryByKey, SortedInsertBy, SortedInsertByKey, SortedInsertComparator,
+    SortedInsertGallop, SortedInsertGallopBy, SortedInsertGallopByKey, SortedInsertMut,
+    SortedInsertMutBy, SortedInsertMutByKey, SortedInsertPartial, SortedInsertPartialByKey,
+    SortedInsertTotal, SortedInsertTotalByKey, SortedInsertWithHint, SortedInsertWithHintBy,
+    SortedInsertWithHintByKey, SortedMerge, SortedMergeBy, SortedMergeByKey, SortedRank,
+    SortedRankBy, SortedRankByKey, SortedSetOperationInPlace, SortedSetOperationInPlaceBy,
+    SortedSetOperationInPlaceByKey, TotalOrd,
+};
+
+impl<T> SortedInsertBasic<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn insert_element(&mut self, index: usize, element: T) {
+        self.insert(index, element);
+    }
+
+    #[inline]
+    fn get_element(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
+}
+
+impl<T> SortedInsertBy<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn get_sorted_insert_index_by<F: FnMut(&T) -> bool>(&self, f: F) -> usize {
+        match self.iter().rposition(f) {
+            Some(i) => i + 1,
+            None => 0,
+        }
+    }
+}
+
+impl<T> SortedInsertByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: Ord> SortedInsert<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedInsertBinaryBy<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn get_sorted_insert_index_binary_by<F: FnMut(&T) -> Ordering>(&mut self, f: F) -> usize {
+        match self.binary_search_by(f) {
+            Ok(i) => i + 1,
+            Err(i) => i,
+        }
+    }
+}
+
+impl<T> SortedInsertBinaryByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: Ord> SortedInsertBinary<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedInsertGallopBy<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&T) -> Ordering>(&mut self, f: F) -> usize {
+        self.gallop_by(self.len(), f)
+    }
+}
+
+impl<T> SortedInsertGallopByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: Ord> SortedInsertGallop<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedInsertWithHintBy<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn get_sorted_insert_index_with_hint_by<F: FnMut(&T) -> Ordering>(
+        &mut self,
+        hint: usize,
+        f: F,
+    ) -> usize {
+        self.gallop_by(hint, f)
+    }
+}
+
+impl<T> SortedInsertWithHintByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: Ord> SortedInsertWithHint<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedInsertTotalByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: TotalOrd> SortedInsertTotal<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedInsertPartialByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: PartialOrd> SortedInsertPartial<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedInsertComparator<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn sorted_binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.binary_search_by(f)
+    }
+}
+
+impl<T> SortedInsertMutBy<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn split_neighbors_mut(&mut self, index: usize) -> (Option<&T>, &mut T, Option<&T>) {
+        self.neighbors_mut(index)
+    }
+}
+
+impl<T> SortedInsertMutByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: Ord> SortedInsertMut<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedEntryBy<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedEntryByKey<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedMergeBy<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn merge_elements_by<I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: I,
+        dedup: bool,
+        f: F,
+    ) {
+        self.with_vec(|v| merge::merge_vec_by(v, other, dedup, f))
+    }
+}
+
+impl<T> SortedMergeByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: Ord> SortedMerge<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedSetOperationInPlaceBy<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn with_elements_vec<R, G: FnOnce(&mut Vec<T>) -> R>(&mut self, g: G) -> R {
+        self.with_vec(g)
+    }
+}
+
+impl<T> SortedSetOperationInPlaceByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: Ord> SortedSetOperationInPlace<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedBulkBy<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn partition_point_elements<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.partition_point(pred)
+    }
+
+    #[inline]
+    fn drain_elements(&mut self, start: usize, end: usize) -> Self {
+        let chunk_size = self.chunk_size();
+        let drained = self.with_vec(|v| v.drain(start..end).collect());
+
+        SortedChunkedVec::from_sorted_vec_unchecked(drained, chunk_size)
+    }
+
+    #[inline]
+    fn retain_elements(&mut self, start: usize, end: usize) {
+        self.with_vec(|v| {
+            v.truncate(end);
+            v.drain(..start);
+        })
+    }
+
+    #[inline]
+    fn split_off_elements(&mut self, index: usize) -> Self {
+        let chunk_size = self.chunk_size();
+        let tail = self.with_vec(|v| v.split_off(index));
+
+        SortedChunkedVec::from_sorted_vec_unchecked(tail, chunk_size)
+    }
+
+    #[inline]
+    fn dedup_elements_by<F: FnMut(&T, &T) -> bool>(&mut self, same_bucket: F, keep: DedupKeep) {
+        self.with_vec(|v| v.sorted_dedup_by(same_bucket, keep))
+    }
+}
+
+impl<T> SortedBulkByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: Ord> SortedBulk<T> for SortedChunkedVec<T> {}
+
+impl<T> SortedRankBy<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn count_elements(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn partition_point_by<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.partition_point(pred)
+    }
+}
+
+impl<T> SortedRankByKey<T> for SortedChunkedVec<T> {}
+
+impl<T: Ord> SortedRank<T> for SortedChunkedVec<T> {}
@@ -1,16 +1,41 @@
 use alloc::vec::Vec;
-use core::cmp::Ordering;
+use core::{cmp::Ordering, mem};
 
 use crate::{
-    SortedInsert, SortedInsertBasic, SortedInsertBinary, SortedInsertBinaryBy,
-    SortedInsertBinaryByKey, SortedInsertBy, SortedInsertByKey,
+    merge, search, DedupKeep, InterpolateKey, SortedBulk, SortedBulkBy, SortedBulkByKey,
+    SortedEntryBy, SortedEntryByKey, SortedInsert, SortedInsertBasic, SortedInsertBinary,
+    SortedInsertBinaryBy, SortedInsertBinaryByKey, SortedInsertBy, SortedInsertByKey,
+    SortedInsertComparator, SortedInsertGallop, SortedInsertGallopBy, SortedInsertGallopByKey,
+    SortedInsertInterpolation, SortedInsertInterpolationByKey, SortedInsertMut, SortedInsertMutBy,
+    SortedInsertMutByKey, SortedInsertPartial, SortedInsertPartialByKey, SortedInsertTotal,
+    SortedInsertTotalByKey, SortedInsertWithHint, SortedInsertWithHintBy,
+    SortedInsertWithHintByKey, SortedMerge, SortedMergeBy, SortedMergeByKey, SortedRank,
+    SortedRankBy, SortedRankByKey, SortedSetOperation, SortedSetOperationBy,
+    SortedSetOperationByKey, SortedSetOperationInPlace, SortedSetOperationInPlaceBy,
+    SortedSetOperationInPlaceByKey, TotalOrd,
 };
 
+impl<T> SortedSetOperationBy<T> for [T] {
+    #[inline]
+    fn as_sorted_slices(&self) -> (&[T], &[T]) {
+        (self, &[])
+    }
+}
+
+impl<T> SortedSetOperationByKey<T> for [T] {}
+
+impl<T: Ord> SortedSetOperation<T> for [T] {}
+
 impl<T> SortedInsertBasic<T> for Vec<T> {
     #[inline]
     fn insert_element(&mut self, index: usize, element: T) {
         self.insert(index, element);
     }
+
+    #[inline]
+    fn get_element(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
 }
 
 impl<T> SortedInsertBy<T> for Vec<T> {
@@ -40,3 +65,163 @@ impl<T> SortedInsertBinaryBy<T> for Vec<T> {
 impl<T> SortedInsertBinaryByKey<T> for Vec<T> {}
 
 impl<T: Ord> SortedInsertBinary<T> for Vec<T> {}
+
+impl<T> SortedInsertGallopBy<T> for Vec<T> {
+    #[inline]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&T) -> Ordering>(&mut self, f: F) -> usize {
+        search::gallop_by(self, self.len(), f)
+    }
+}
+
+impl<T> SortedInsertGallopByKey<T> for Vec<T> {}
+
+impl<T: Ord> SortedInsertGallop<T> for Vec<T> {}
+
+impl<T> SortedInsertWithHintBy<T> for Vec<T> {
+    #[inline]
+    fn get_sorted_insert_index_with_hint_by<F: FnMut(&T) -> Ordering>(
+        &mut self,
+        hint: usize,
+        f: F,
+    ) -> usize {
+        search::gallop_by(self, hint, f)
+    }
+}
+
+impl<T> SortedInsertWithHintByKey<T> for Vec<T> {}
+
+impl<T: Ord> SortedInsertWithHint<T> for Vec<T> {}
+
+impl<T> SortedInsertInterpolationByKey<T> for Vec<T> {
+    #[inline]
+    fn get_sorted_insert_index_interpolation_by_key<A: InterpolateKey, F: FnMut(&T) -> &A>(
+        &mut self,
+        key: &A,
+        f: F,
+        descending: bool,
+    ) -> usize {
+        search::interpolation_by_key(self, key, f, descending)
+    }
+}
+
+impl<T: InterpolateKey> SortedInsertInterpolation<T> for Vec<T> {}
+
+impl<T> SortedInsertTotalByKey<T> for Vec<T> {}
+
+impl<T: TotalOrd> SortedInsertTotal<T> for Vec<T> {}
+
+impl<T> SortedInsertPartialByKey<T> for Vec<T> {}
+
+impl<T: PartialOrd> SortedInsertPartial<T> for Vec<T> {}
+
+impl<T> SortedInsertComparator<T> for Vec<T> {
+    #[inline]
+    fn sorted_binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.binary_search_by(f)
+    }
+}
+
+impl<T> SortedInsertMutBy<T> for Vec<T> {
+    #[inline]
+    fn split_neighbors_mut(&mut self, index: usize) -> (Option<&T>, &mut T, Option<&T>) {
+        let (left, right) = self.split_at_mut(index);
+        let (element, right) = right.split_first_mut().unwrap();
+
+        (left.last(), element, right.first())
+    }
+}
+
+impl<T> SortedInsertMutByKey<T> for Vec<T> {}
+
+impl<T: Ord> SortedInsertMut<T> for Vec<T> {}
+
+impl<T> SortedEntryBy<T> for Vec<T> {}
+
+impl<T> SortedEntryByKey<T> for Vec<T> {}
+
+impl<T> SortedMergeBy<T> for Vec<T> {
+    #[inline]
+    fn merge_elements_by<I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: I,
+        dedup: bool,
+        f: F,
+    ) {
+        merge::merge_vec_by(self, other, dedup, f)
+    }
+}
+
+impl<T> SortedMergeByKey<T> for Vec<T> {}
+
+impl<T: Ord> SortedMerge<T> for Vec<T> {}
+
+impl<T> SortedSetOperationInPlaceBy<T> for Vec<T> {
+    #[inline]
+    fn with_elements_vec<R, G: FnOnce(&mut Vec<T>) -> R>(&mut self, g: G) -> R {
+        g(self)
+    }
+}
+
+impl<T> SortedSetOperationInPlaceByKey<T> for Vec<T> {}
+
+impl<T: Ord> SortedSetOperationInPlace<T> for Vec<T> {}
+
+impl<T> SortedBulkBy<T> for Vec<T> {
+    #[inline]
+    fn partition_point_elements<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.partition_point(pred)
+    }
+
+    #[inline]
+    fn drain_elements(&mut self, start: usize, end: usize) -> Self {
+        self.drain(start..end).collect()
+    }
+
+    #[inline]
+    fn retain_elements(&mut self, start: usize, end: usize) {
+        self.truncate(end);
+        self.drain(..start);
+    }
+
+    #[inline]
+    fn split_off_elements(&mut self, index: usize) -> Self {
+        self.split_off(index)
+    }
+
+    #[inline]
+    fn dedup_elements_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same_bucket: F, keep: DedupKeep) {
+        match keep {
+            DedupKeep::First => self.dedup_by(|later, earlier| same_bucket(earlier, later)),
+            DedupKeep::Last => self.dedup_by(|later, earlier| {
+                if same_bucket(earlier, later) {
+                    // move the later element into the slot which is kept
+                    mem::swap(later, earlier);
+
+                    true
+                } else {
+                    false
+                }
+            }),
+        }
+    }
+}
+
+impl<T> SortedBulkByKey<T> for Vec<T> {}
+
+impl<T: Ord> SortedBulk<T> for Vec<T> {}
+
+impl<T> SortedRankBy<T> for Vec<T> {
+    #[inline]
+    fn count_elements(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn partition_point_by<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.partition_point(pred)
+    }
+}
+
+impl<T> SortedRankByKey<T> for Vec<T> {}
+
+impl<T: Ord> SortedRank<T> for Vec<T> {}
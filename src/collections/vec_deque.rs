@@ -1,9 +1,18 @@
-use core::cmp::Ordering;
+use core::{cmp::Ordering, mem};
 use std::collections::VecDeque;
 
 use crate::{
-    SortedInsert, SortedInsertBasic, SortedInsertBinary, SortedInsertBinaryBy,
-    SortedInsertBinaryByKey, SortedInsertBy, SortedInsertByKey,
+    merge, search, DedupKeep, InterpolateKey, SortedBulk, SortedBulkBy, SortedBulkByKey,
+    SortedEntryBy, SortedEntryByKey, SortedInsert, SortedInsertBasic, SortedInsertBinary,
+    SortedInsertBinaryBy, SortedInsertBinaryByKey, SortedInsertBy, SortedInsertByKey,
+    SortedInsertComparator, SortedInsertGallop, SortedInsertGallopBy, SortedInsertGallopByKey,
+    SortedInsertInterpolation, SortedInsertInterpolationByKey, SortedInsertMut, SortedInsertMutBy,
+    SortedInsertMutByKey, SortedInsertPartial, SortedInsertPartialByKey, SortedInsertTotal,
+    SortedInsertTotalByKey, SortedInsertWithHint, SortedInsertWithHintBy,
+    SortedInsertWithHintByKey, SortedMerge, SortedMergeBy, SortedMergeByKey, SortedRank,
+    SortedRankBy, SortedRankByKey, SortedSetOperation, SortedSetOperationBy,
+    SortedSetOperationByKey, SortedSetOperationInPlace, SortedSetOperationInPlaceBy,
+    SortedSetOperationInPlaceByKey, TotalOrd,
 };
 
 impl<T> SortedInsertBasic<T> for VecDeque<T> {
@@ -11,6 +20,11 @@ impl<T> SortedInsertBasic<T> for VecDeque<T> {
     fn insert_element(&mut self, index: usize, element: T) {
         self.insert(index, element);
     }
+
+    #[inline]
+    fn get_element(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
 }
 
 impl<T> SortedInsertBy<T> for VecDeque<T> {
@@ -40,3 +54,187 @@ impl<T> SortedInsertBinaryBy<T> for VecDeque<T> {
 impl<T> SortedInsertBinaryByKey<T> for VecDeque<T> {}
 
 impl<T: Ord> SortedInsertBinary<T> for VecDeque<T> {}
+
+impl<T> SortedInsertGallopBy<T> for VecDeque<T> {
+    #[inline]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&T) -> Ordering>(&mut self, f: F) -> usize {
+        let len = self.len();
+
+        search::gallop_by(self.make_contiguous(), len, f)
+    }
+}
+
+impl<T> SortedInsertGallopByKey<T> for VecDeque<T> {}
+
+impl<T: Ord> SortedInsertGallop<T> for VecDeque<T> {}
+
+impl<T> SortedInsertWithHintBy<T> for VecDeque<T> {
+    #[inline]
+    fn get_sorted_insert_index_with_hint_by<F: FnMut(&T) -> Ordering>(
+        &mut self,
+        hint: usize,
+        f: F,
+    ) -> usize {
+        search::gallop_by(self.make_contiguous(), hint, f)
+    }
+}
+
+impl<T> SortedInsertWithHintByKey<T> for VecDeque<T> {}
+
+impl<T: Ord> SortedInsertWithHint<T> for VecDeque<T> {}
+
+impl<T> SortedInsertInterpolationByKey<T> for VecDeque<T> {
+    #[inline]
+    fn get_sorted_insert_index_interpolation_by_key<A: InterpolateKey, F: FnMut(&T) -> &A>(
+        &mut self,
+        key: &A,
+        f: F,
+        descending: bool,
+    ) -> usize {
+        search::interpolation_by_key(self.make_contiguous(), key, f, descending)
+    }
+}
+
+impl<T: InterpolateKey> SortedInsertInterpolation<T> for VecDeque<T> {}
+
+impl<T> SortedInsertTotalByKey<T> for VecDeque<T> {}
+
+impl<T: TotalOrd> SortedInsertTotal<T> for VecDeque<T> {}
+
+impl<T> SortedInsertPartialByKey<T> for VecDeque<T> {}
+
+impl<T: PartialOrd> SortedInsertPartial<T> for VecDeque<T> {}
+
+impl<T> SortedInsertComparator<T> for VecDeque<T> {
+    #[inline]
+    fn sorted_binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize> {
+        self.binary_search_by(f)
+    }
+}
+
+impl<T> SortedInsertMutBy<T> for VecDeque<T> {
+    #[inline]
+    fn split_neighbors_mut(&mut self, index: usize) -> (Option<&T>, &mut T, Option<&T>) {
+        let (front, back) = self.as_mut_slices();
+
+        if index < front.len() {
+            let (left, right) = front.split_at_mut(index);
+            let (element, right) = right.split_first_mut().unwrap();
+
+            (left.last(), element, right.first().or_else(|| back.first()))
+        } else {
+            let (left, right) = back.split_at_mut(index - front.len());
+            let (element, right) = right.split_first_mut().unwrap();
+
+            (left.last().or_else(|| front.last()), element, right.first())
+        }
+    }
+}
+
+impl<T> SortedInsertMutByKey<T> for VecDeque<T> {}
+
+impl<T: Ord> SortedInsertMut<T> for VecDeque<T> {}
+
+impl<T> SortedEntryBy<T> for VecDeque<T> {}
+
+impl<T> SortedEntryByKey<T> for VecDeque<T> {}
+
+impl<T> SortedMergeBy<T> for VecDeque<T> {
+    #[inline]
+    fn merge_elements_by<I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: I,
+        dedup: bool,
+        f: F,
+    ) {
+        let mut v = Vec::from(mem::take(self));
+
+        merge::merge_vec_by(&mut v, other, dedup, f);
+
+        *self = VecDeque::from(v);
+    }
+}
+
+impl<T> SortedMergeByKey<T> for VecDeque<T> {}
+
+impl<T: Ord> SortedMerge<T> for VecDeque<T> {}
+
+impl<T> SortedSetOperationBy<T> for VecDeque<T> {
+    #[inline]
+    fn as_sorted_slices(&self) -> (&[T], &[T]) {
+        self.as_slices()
+    }
+}
+
+impl<T> SortedSetOperationByKey<T> for VecDeque<T> {}
+
+impl<T: Ord> SortedSetOperation<T> for VecDeque<T> {}
+
+impl<T> SortedSetOperationInPlaceBy<T> for VecDeque<T> {
+    #[inline]
+    fn with_elements_vec<R, G: FnOnce(&mut Vec<T>) -> R>(&mut self, g: G) -> R {
+        let mut v = Vec::from(mem::take(self));
+
+        let result = g(&mut v);
+
+        *self = VecDeque::from(v);
+
+        result
+    }
+}
+
+impl<T> SortedSetOperationInPlaceByKey<T> for VecDeque<T> {}
+
+impl<T: Ord> SortedSetOperationInPlace<T> for VecDeque<T> {}
+
+impl<T> SortedBulkBy<T> for VecDeque<T> {
+    #[inline]
+    fn partition_point_elements<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.partition_point(pred)
+    }
+
+    #[inline]
+    fn drain_elements(&mut self, start: usize, end: usize) -> Self {
+        self.drain(start..end).collect()
+    }
+
+    #[inline]
+    fn retain_elements(&mut self, start: usize, end: usize) {
+        self.truncate(end);
+        self.drain(..start);
+    }
+
+    #[inline]
+    fn split_off_elements(&mut self, index: usize) -> Self {
+        self.split_off(index)
+    }
+
+    #[inline]
+    fn dedup_elements_by<F: FnMut(&T, &T) -> bool>(&mut self, same_bucket: F, keep: DedupKeep) {
+        let mut v = Vec::from(mem::take(self));
+
+        v.sorted_dedup_by(same_bucket, keep);
+
+        *self = VecDeque::from(v);
+    }
+}
+
+impl<T> SortedBulkByKey<T> for VecDeque<T> {}
+
+impl<T: Ord> SortedBulk<T> for VecDeque<T> {}
+
+impl<T> SortedRankBy<T> for VecDeque<T> {
+    #[inline]
+    fn count_elements(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn partition_point_by<P: FnMut(&T) -> bool>(&self, pred: P) -> usize {
+        self.partition_point(pred)
+    }
+}
+
+impl<T> SortedRankByKey<T> for VecDeque<T> {}
+
+impl<T: Ord> SortedRank<T> for VecDeque<T> {}
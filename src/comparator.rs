@@ -0,0 +1,247 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+use crate::{Order, TotalOrd};
+
+/// A reusable ordering of values of type `T`, so that the ordering of a collection can be defined once, stored and passed to the insertion and search methods.
+///
+/// Closures of `Fn(&T, &T) -> Ordering` are comparators as well.
+///
+/// ```rust
+/// use sorted_insert::{Comparator, Natural, SortedInsertComparator};
+///
+/// // larger numbers first, then by letters
+/// let comparator = Natural::new()
+///     .by_key(|e: &(u8, char)| &e.0)
+///     .reverse()
+///     .then(Natural::new().by_key(|e: &(u8, char)| &e.1));
+///
+/// let mut v = Vec::new();
+///
+/// v.sorted_insert_binary_by_comparator((30, 'b'), &comparator);
+/// v.sorted_insert_binary_by_comparator((40, 'c'), &comparator);
+/// v.sorted_insert_binary_by_comparator((30, 'a'), &comparator);
+///
+/// assert_eq!([(40, 'c'), (30, 'a'), (30, 'b')], v.as_slice());
+/// ```
+pub trait Comparator<T: ?Sized> {
+    /// Compare two values.
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+
+    /// Create a comparator which compares by this comparator first, and then by `other` if the values are equal.
+    #[inline]
+    fn then<C: Comparator<T>>(self, other: C) -> Then<Self, C>
+    where
+        Self: Sized, {
+        Then {
+            first: self, second: other
+        }
+    }
+
+    /// Create a comparator which is the reverse of this comparator.
+    #[inline]
+    fn reverse(self) -> Reversed<Self>
+    where
+        Self: Sized, {
+        Reversed(self)
+    }
+
+    /// Create a comparator of `U` which compares the keys extracted by `f` with this comparator.
+    #[inline]
+    fn by_key<U: ?Sized, F: Fn(&U) -> &T>(self, f: F) -> ByKey<Self, F, T>
+    where
+        Self: Sized, {
+        ByKey {
+            comparator: self,
+            f,
+            _key: PhantomData,
+        }
+    }
+
+    /// Create a comparator of `Option<T>` which puts `None` before any `Some` and compares `Some` values with this comparator.
+    #[inline]
+    fn nulls_first(self) -> NullsFirst<Self>
+    where
+        Self: Sized, {
+        NullsFirst(self)
+    }
+
+    /// Create a comparator of `Option<T>` which puts `None` after any `Some` and compares `Some` values with this comparator.
+    #[inline]
+    fn nulls_last(self) -> NullsLast<Self>
+    where
+        Self: Sized, {
+        NullsLast(self)
+    }
+}
+
+impl<T: ?Sized, F: Fn(&T, &T) -> Ordering> Comparator<T> for F {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+impl<T: Ord + ?Sized> Comparator<T> for Order {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        Order::compare(*self, a, b)
+    }
+}
+
+/// The comparator of the `Ord` implementation.
+pub struct Natural<T: ?Sized>(PhantomData<fn(&T)>);
+
+impl<T: ?Sized> Natural<T> {
+    /// Create the comparator of the `Ord` implementation.
+    #[inline]
+    pub const fn new() -> Self {
+        Natural(PhantomData)
+    }
+}
+
+impl<T: Ord + ?Sized> Comparator<T> for Natural<T> {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// The comparator of the `TotalOrd` implementation, such as the total order of floating-point numbers.
+pub struct Total<T: ?Sized>(PhantomData<fn(&T)>);
+
+impl<T: ?Sized> Total<T> {
+    /// Create the comparator of the `TotalOrd` implementation.
+    #[inline]
+    pub const fn new() -> Self {
+        Total(PhantomData)
+    }
+}
+
+impl<T: TotalOrd + ?Sized> Comparator<T> for Total<T> {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.total_cmp(b)
+    }
+}
+
+macro_rules! impl_marker_traits {
+    ($($name:ident),* $(,)?) => {
+        $(
+            impl<T: ?Sized> Debug for $name<T> {
+                #[inline]
+                fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                    f.write_str(stringify!($name))
+                }
+            }
+
+            impl<T: ?Sized> Clone for $name<T> {
+                #[inline]
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<T: ?Sized> Copy for $name<T> {}
+
+            impl<T: ?Sized> Default for $name<T> {
+                #[inline]
+                fn default() -> Self {
+                    $name::new()
+                }
+            }
+        )*
+    };
+}
+
+impl_marker_traits!(Natural, Total);
+
+/// The comparator created by `Comparator::then`.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Then<A, B> {
+    first:  A,
+    second: B,
+}
+
+impl<T: ?Sized, A: Comparator<T>, B: Comparator<T>> Comparator<T> for Then<A, B> {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        match self.first.compare(a, b) {
+            Ordering::Equal => self.second.compare(a, b),
+            ordering => ordering,
+        }
+    }
+}
+
+/// The comparator created by `Comparator::reverse`.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Reversed<C>(C);
+
+impl<T: ?Sized, C: Comparator<T>> Comparator<T> for Reversed<C> {
+    #[inline]
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.0.compare(b, a)
+    }
+}
+
+/// The comparator created by `Comparator::by_key`.
+pub struct ByKey<C, F, K: ?Sized> {
+    comparator: C,
+    f:          F,
+    _key:       PhantomData<fn(&K)>,
+}
+
+impl<U: ?Sized, K: ?Sized, C: Comparator<K>, F: Fn(&U) -> &K> Comparator<U> for ByKey<C, F, K> {
+    #[inline]
+    fn compare(&self, a: &U, b: &U) -> Ordering {
+        self.comparator.compare((self.f)(a), (self.f)(b))
+    }
+}
+
+impl<C: Clone, F: Clone, K: ?Sized> Clone for ByKey<C, F, K> {
+    #[inline]
+    fn clone(&self) -> Self {
+        ByKey {
+            comparator: self.comparator.clone(),
+            f:          self.f.clone(),
+            _key:       PhantomData,
+        }
+    }
+}
+
+impl<C: Copy, F: Copy, K: ?Sized> Copy for ByKey<C, F, K> {}
+
+/// The comparator created by `Comparator::nulls_first`.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct NullsFirst<C>(C);
+
+impl<T, C: Comparator<T>> Comparator<Option<T>> for NullsFirst<C> {
+    #[inline]
+    fn compare(&self, a: &Option<T>, b: &Option<T>) -> Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) => self.0.compare(a, b),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+        }
+    }
+}
+
+/// The comparator created by `Comparator::nulls_last`.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct NullsLast<C>(C);
+
+impl<T, C: Comparator<T>> Comparator<Option<T>> for NullsLast<C> {
+    #[inline]
+    fn compare(&self, a: &Option<T>, b: &Option<T>) -> Ordering {
+        match (a, b) {
+            (Some(a), Some(b)) => self.0.compare(a, b),
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(_), None) => Ordering::Less,
+        }
+    }
+}
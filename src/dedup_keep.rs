@@ -0,0 +1,15 @@
+/// Which element of each run of equal elements is kept by `sorted_dedup_by` and its variants.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum DedupKeep {
+    /// Keep the first element of each run.
+    First,
+    /// Keep the last element of each run.
+    Last,
+}
+
+impl Default for DedupKeep {
+    #[inline]
+    fn default() -> Self {
+        DedupKeep::First
+    }
+}
@@ -0,0 +1,178 @@
+#[cfg(feature = "debug-checks")]
+use alloc::boxed::Box;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+use crate::SortedInsertMutBy;
+
+/// Find the entry of the searched key with binary search.
+pub(crate) fn find<'a, C: SortedInsertMutBy<T> + ?Sized, T, F: FnMut(&T) -> Ordering + 'a>(
+    collection: &'a mut C,
+    mut f: F,
+) -> SortedEntry<'a, C, T> {
+    let index = collection.get_sorted_insert_index_binary_by(&mut f);
+
+    // the binary search returns the index after a matching element, if there is one
+    if index > 0 && f(collection.get_element(index - 1).unwrap()) == Ordering::Equal {
+        SortedEntry::Occupied(SortedOccupiedEntry {
+            collection,
+            index: index - 1,
+            _element: PhantomData,
+        })
+    } else {
+        SortedEntry::Vacant(SortedVacantEntry {
+            collection,
+            index,
+            #[cfg(feature = "debug-checks")]
+            search: Box::new(f),
+            _element: PhantomData,
+        })
+    }
+}
+
+/// A view into a position of a sorted collection, which is either occupied by an element with the searched key or vacant, returned by `sorted_entry_*` methods.
+pub enum SortedEntry<'a, C: ?Sized, T> {
+    Occupied(SortedOccupiedEntry<'a, C, T>),
+    Vacant(SortedVacantEntry<'a, C, T>),
+}
+
+/// An entry of an element with the searched key.
+pub struct SortedOccupiedEntry<'a, C: ?Sized, T> {
+    collection: &'a mut C,
+    index:      usize,
+    _element:   PhantomData<fn() -> T>,
+}
+
+/// An entry of the index where an element with the searched key can be inserted.
+pub struct SortedVacantEntry<'a, C: ?Sized, T> {
+    collection: &'a mut C,
+    index:      usize,
+    /// The search is kept to check the key of the inserted element.
+    #[cfg(feature = "debug-checks")]
+    search:     Box<dyn FnMut(&T) -> Ordering + 'a>,
+    _element:   PhantomData<fn() -> T>,
+}
+
+impl<'a, C: SortedInsertMutBy<T> + ?Sized, T> SortedEntry<'a, C, T> {
+    /// Return the index of the element if the entry is occupied, or the index where an element can be inserted if it is vacant.
+    #[inline]
+    pub fn index(&self) -> usize {
+        match self {
+            SortedEntry::Occupied(entry) => entry.index(),
+            SortedEntry::Vacant(entry) => entry.index(),
+        }
+    }
+
+    /// Return a mutable reference to the element if the entry is occupied, or insert `element` and return a mutable reference to it. `element` must have the searched key.
+    #[inline]
+    pub fn or_insert(self, element: T) -> &'a mut T {
+        match self {
+            SortedEntry::Occupied(entry) => entry.into_mut(),
+            SortedEntry::Vacant(entry) => entry.insert(element),
+        }
+    }
+
+    /// Return a mutable reference to the element if the entry is occupied, or insert the element created by `f` and return a mutable reference to it. The element must have the searched key.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> T>(self, f: F) -> &'a mut T {
+        match self {
+            SortedEntry::Occupied(entry) => entry.into_mut(),
+            SortedEntry::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    /// Modify the element with `f` if the entry is occupied. The key of the element must not be changed.
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut T)>(mut self, f: F) -> Self {
+        if let SortedEntry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+}
+
+impl<'a, C: SortedInsertMutBy<T> + ?Sized, T> SortedOccupiedEntry<'a, C, T> {
+    /// Return the index of the element.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Return a reference to the element.
+    #[inline]
+    pub fn get(&self) -> &T {
+        self.collection.get_element(self.index).unwrap()
+    }
+
+    /// Return a mutable reference to the element. The key of the element must not be changed.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.collection.split_neighbors_mut(self.index).1
+    }
+
+    /// Convert this entry into a mutable reference to the element. The key of the element must not be changed.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut T {
+        self.collection.split_neighbors_mut(self.index).1
+    }
+}
+
+impl<'a, C: SortedInsertMutBy<T> + ?Sized, T> SortedVacantEntry<'a, C, T> {
+    /// Return the index where an element with the searched key can be inserted.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Insert `element`, which must have the searched key, and return a mutable reference to it.
+    #[inline]
+    #[allow(unused_mut)]
+    pub fn insert(mut self, element: T) -> &'a mut T {
+        #[cfg(feature = "debug-checks")]
+        if (self.search)(&element) != Ordering::Equal {
+            panic!(
+                "the collection is not sorted: the element inserted at index {} does not have the \
+                 searched key",
+                self.index
+            );
+        }
+
+        self.collection.insert_element(self.index, element);
+
+        self.collection.split_neighbors_mut(self.index).1
+    }
+}
+
+impl<'a, C: Debug + ?Sized, T> Debug for SortedEntry<'a, C, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SortedEntry::Occupied(entry) => f.debug_tuple("Occupied").field(entry).finish(),
+            SortedEntry::Vacant(entry) => f.debug_tuple("Vacant").field(entry).finish(),
+        }
+    }
+}
+
+impl<'a, C: Debug + ?Sized, T> Debug for SortedOccupiedEntry<'a, C, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedOccupiedEntry")
+            .field("collection", &self.collection)
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<'a, C: Debug + ?Sized, T> Debug for SortedVacantEntry<'a, C, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedVacantEntry")
+            .field("collection", &self.collection)
+            .field("index", &self.index)
+            .finish()
+    }
+}
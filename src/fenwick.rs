@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+/// A Fenwick tree over a sequence of counts, such as the counts of the runs of a `SortedBag` or the lengths of the chunks of a `SortedChunkedVec`, which adds up the counts before a slot and finds the slot of a position in `O(log m)` for `m` slots.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct Fenwick {
+    /// `tree[k - 1]` is the sum of the counts of the slots from `k - lowbit(k)` to `k - 1`.
+    tree: Vec<usize>,
+}
+
+#[inline]
+fn lowbit(k: usize) -> usize {
+    k & k.wrapping_neg()
+}
+
+impl Fenwick {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Fenwick {
+            tree: Vec::new()
+        }
+    }
+
+    /// Build the tree from the counts of all the slots in `O(m)`.
+    pub(crate) fn build<I: IntoIterator<Item = usize>>(counts: I) -> Self {
+        let mut tree: Vec<usize> = counts.into_iter().collect();
+        let n = tree.len();
+
+        for k in 1..=n {
+            let parent = k + lowbit(k);
+
+            if parent <= n {
+                tree[parent - 1] += tree[k - 1];
+            }
+        }
+
+        Fenwick {
+            tree,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.tree.clear();
+    }
+
+    /// Add `n` to the count of the slot at `index`.
+    pub(crate) fn add(&mut self, index: usize, n: usize) {
+        let mut k = index + 1;
+
+        while k <= self.tree.len() {
+            self.tree[k - 1] += n;
+            k += lowbit(k);
+        }
+    }
+
+    /// Subtract `n` from the count of the slot at `index`.
+    pub(crate) fn sub(&mut self, index: usize, n: usize) {
+        let mut k = index + 1;
+
+        while k <= self.tree.len() {
+            self.tree[k - 1] -= n;
+            k += lowbit(k);
+        }
+    }
+
+    /// Return the sum of the counts of the slots before `end`.
+    pub(crate) fn prefix_sum(&self, end: usize) -> usize {
+        let mut k = end;
+        let mut sum = 0;
+
+        while k > 0 {
+            sum += self.tree[k - 1];
+            k -= lowbit(k);
+        }
+
+        sum
+    }
+
+    /// Return the index of the slot which contains the position `rank`, which must be less than the sum of all the counts.
+    pub(crate) fn select(&self, mut rank: usize) -> usize {
+        let n = self.tree.len();
+
+        if n == 0 {
+            return 0;
+        }
+
+        let mut index = 0;
+        let mut step = 1 << (usize::BITS - 1 - n.leading_zeros());
+
+        // descend the tree and skip every block whose counts are all before `rank`
+        while step > 0 {
+            if index + step <= n && self.tree[index + step - 1] <= rank {
+                index += step;
+                rank -= self.tree[index - 1];
+            }
+
+            step >>= 1;
+        }
+
+        index
+    }
+}
@@ -0,0 +1,47 @@
+use core::cmp::Ordering;
+
+/// Compare two `f32` values by the total ordering defined in IEEE 754, the same as `f32::total_cmp`.
+#[inline]
+pub(crate) fn total_cmp_f32(a: f32, b: f32) -> Ordering {
+    let mut a = a.to_bits() as i32;
+    let mut b = b.to_bits() as i32;
+
+    a ^= (((a >> 31) as u32) >> 1) as i32;
+    b ^= (((b >> 31) as u32) >> 1) as i32;
+
+    a.cmp(&b)
+}
+
+/// Compare two `f64` values by the total ordering defined in IEEE 754, the same as `f64::total_cmp`.
+#[inline]
+pub(crate) fn total_cmp_f64(a: f64, b: f64) -> Ordering {
+    let mut a = a.to_bits() as i64;
+    let mut b = b.to_bits() as i64;
+
+    a ^= (((a >> 63) as u64) >> 1) as i64;
+    b ^= (((b >> 63) as u64) >> 1) as i64;
+
+    a.cmp(&b)
+}
+
+/// Types which have a total order besides their `PartialOrd` implementation, such as the IEEE 754 `totalOrder` predicate of floating-point numbers.
+///
+/// Under this order, negative quiet NaNs come first, then negative infinity, negative numbers, `-0.0`, `+0.0`, positive numbers, positive infinity and positive quiet NaNs.
+pub trait TotalOrd {
+    /// Compare this value with another value by the total order.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl TotalOrd for f32 {
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        total_cmp_f32(*self, *other)
+    }
+}
+
+impl TotalOrd for f64 {
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        total_cmp_f64(*self, *other)
+    }
+}
@@ -0,0 +1,67 @@
+use core::ops::{Deref, DerefMut};
+
+/// A mutable handle to an element which has just been inserted to a sorted collection, returned by `sorted_insert_*_mut` methods. It also gives access to the elements right before and after the inserted one.
+///
+/// Mutating the element must not change its position in the order of the collection.
+#[derive(Debug)]
+pub struct SortedInsertedMut<'a, T> {
+    index:       usize,
+    predecessor: Option<&'a T>,
+    element:     &'a mut T,
+    successor:   Option<&'a T>,
+}
+
+impl<'a, T> SortedInsertedMut<'a, T> {
+    #[inline]
+    pub(crate) fn new(
+        index: usize,
+        (predecessor, element, successor): (Option<&'a T>, &'a mut T, Option<&'a T>),
+    ) -> Self {
+        SortedInsertedMut {
+            index,
+            predecessor,
+            element,
+            successor,
+        }
+    }
+
+    /// Return the index of the inserted element.
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Return the element right before the inserted one, or `None` if the inserted element is the first one.
+    #[inline]
+    pub const fn predecessor(&self) -> Option<&'a T> {
+        self.predecessor
+    }
+
+    /// Return the element right after the inserted one, or `None` if the inserted element is the last one.
+    #[inline]
+    pub const fn successor(&self) -> Option<&'a T> {
+        self.successor
+    }
+
+    /// Convert this handle into a mutable reference to the inserted element.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut T {
+        self.element
+    }
+}
+
+impl<'a, T> Deref for SortedInsertedMut<'a, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.element
+    }
+}
+
+impl<'a, T> DerefMut for SortedInsertedMut<'a, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.element
+    }
+}
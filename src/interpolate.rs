@@ -0,0 +1,93 @@
+use core::{cmp::Ordering, time::Duration};
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+use crate::float::{total_cmp_f32, total_cmp_f64};
+
+/// Keys which can be located by interpolation search.
+pub trait InterpolateKey {
+    /// Compare this key with another key. This must be a total order.
+    fn compare(&self, other: &Self) -> Ordering;
+
+    /// Estimate where this key lies between `low` and `high`, where `low` is not greater than `high`, as a fraction from `0.0` (at `low`) to `1.0` (at `high`). Out-of-range or `NaN` results are allowed, and they are clamped or treated as the middle.
+    fn fraction(&self, low: &Self, high: &Self) -> f64;
+}
+
+macro_rules! impl_interpolate_key_for_integer {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl InterpolateKey for $t {
+                #[inline]
+                fn compare(&self, other: &Self) -> Ordering {
+                    self.cmp(other)
+                }
+
+                #[inline]
+                fn fraction(&self, low: &Self, high: &Self) -> f64 {
+                    (*self as f64 - *low as f64) / (*high as f64 - *low as f64)
+                }
+            }
+        )*
+    };
+}
+
+impl_interpolate_key_for_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl InterpolateKey for f32 {
+    #[inline]
+    fn compare(&self, other: &Self) -> Ordering {
+        total_cmp_f32(*self, *other)
+    }
+
+    #[inline]
+    fn fraction(&self, low: &Self, high: &Self) -> f64 {
+        (*self as f64 - *low as f64) / (*high as f64 - *low as f64)
+    }
+}
+
+impl InterpolateKey for f64 {
+    #[inline]
+    fn compare(&self, other: &Self) -> Ordering {
+        total_cmp_f64(*self, *other)
+    }
+
+    #[inline]
+    fn fraction(&self, low: &Self, high: &Self) -> f64 {
+        (*self - *low) / (*high - *low)
+    }
+}
+
+impl InterpolateKey for Duration {
+    #[inline]
+    fn compare(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+
+    #[inline]
+    fn fraction(&self, low: &Self, high: &Self) -> f64 {
+        (self.as_secs_f64() - low.as_secs_f64()) / (high.as_secs_f64() - low.as_secs_f64())
+    }
+}
+
+#[cfg(feature = "std")]
+impl InterpolateKey for SystemTime {
+    #[inline]
+    fn compare(&self, other: &Self) -> Ordering {
+        self.cmp(other)
+    }
+
+    #[inline]
+    fn fraction(&self, low: &Self, high: &Self) -> f64 {
+        let offset = match self.duration_since(*low) {
+            Ok(offset) => offset.as_secs_f64(),
+            Err(error) => -error.duration().as_secs_f64(),
+        };
+
+        let width = match high.duration_since(*low) {
+            Ok(width) => width.as_secs_f64(),
+            Err(_) => return f64::NAN,
+        };
+
+        offset / width
+    }
+}
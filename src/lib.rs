@@ -38,6 +38,25 @@ v.sorted_insert_asc_by_key(A(1, 15), |e| &e.1);
 assert_eq!([A(1, 10), A(1, 15), A(2, 20)], v.as_slice());
 ```
 
+```rust
+use sorted_insert::SortedVecMap;
+
+let mut map = SortedVecMap::new();
+
+map.insert(2, "b");
+map.insert(1, "a");
+
+assert_eq!([(1, "a"), (2, "b")], map.as_slice());
+```
+
+```rust
+use sorted_insert::SortedCollect;
+
+let v = vec![3, 1, 2].into_iter().collect_sorted_asc();
+
+assert_eq!([1, 2, 3], v.as_slice());
+```
+
 ## No Std
 
 Disable the default features to compile this crate without std.
@@ -47,13 +66,98 @@ Disable the default features to compile this crate without std.
 version = "*"
 default-features = false
 ```
+
+## Debug Checks
+
+This crate trusts that the collections are already sorted. Enable the `debug-checks` feature to check the neighbourhood of the insertion point after each insertion into a `Vec` or a `VecDeque`, which panics with the offending indices if the collection turns out not to be sorted. The `check_sorted_*` and `is_sorted_*` functions can be used to validate a whole collection.
+
+```toml
+[dependencies.sorted-insert]
+version = "*"
+features = ["debug-checks"]
+```
+
+## Serde
+
+Enable the `serde` feature to serialize and deserialize `SortedVecMap`, `SortedBag` and `SortedChunkedVec`. Deserialization rejects out-of-order input, unless the field uses `#[serde(with = "sorted_insert::serde::resort")]` to sort it instead. The `sorted_insert::serde::{asc, desc, asc_resort, desc_resort}` modules do the same for plain `Vec` fields.
+
+```toml
+[dependencies.sorted-insert]
+version = "*"
+features = ["serde"]
+```
+
+## Rayon
+
+Enable the `rayon` feature to insert a large batch of elements into a sorted `Vec` with the `par_sorted_extend_*` methods, which sort the batch and merge it into the `Vec` in parallel.
+
+```toml
+[dependencies.sorted-insert]
+version = "*"
+features = ["rayon"]
+```
+
+## Property Testing
+
+Enable the `proptest` feature to use the strategies in the `proptest` module, or the `quickcheck` feature to use the `Arbitrary` newtypes in the `quickcheck` module. Both generate sorted `Vec`s and `VecDeque`s, `SortedVecMap`s, `SortedBag`s, `SortedChunkedVec`s and insertion sequences with many duplicates. The `arbitrary` feature implements `Arbitrary` of the `arbitrary` crate for `SortedVecMap`, `SortedBag` and `SortedChunkedVec`, for fuzzing.
+
+```toml
+[dev-dependencies.sorted-insert]
+version = "*"
+features = ["proptest", "quickcheck", "arbitrary"]
+```
+
+## Testing
+
+Enable the `testing` feature to check your own implementations of the insertion traits against a reference model with the `testing` module. It runs scripted or random sequences of insertions, each of which calls one of the given insertion strategies, such as linear, binary, galloping or hinted insertion, or a closure for the `*ByKey` traits and the lock collections, and reports the first divergence in the returned index or the contents.
+
+```toml
+[dev-dependencies.sorted-insert]
+version = "*"
+features = ["testing"]
+```
 */
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod check;
+mod collect;
 mod collections;
+mod comparator;
+mod dedup_keep;
+mod entry;
+mod fenwick;
+mod float;
+mod inserted_mut;
+mod interpolate;
+mod merge;
+mod nan_policy;
+mod order;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+mod search;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod set_operation;
+#[cfg(feature = "std")]
+pub mod sliding_quantile;
+pub mod sorted_bag;
+pub mod sorted_chunked_vec;
+pub mod sorted_vec_map;
+#[cfg(feature = "std")]
+pub mod sorted_window;
+#[cfg(feature = "std")]
+pub mod stable_scheduler;
+#[cfg(feature = "testing")]
+pub mod testing;
 
 #[cfg(feature = "std")]
 mod arc_mutex;
@@ -61,17 +165,44 @@ mod arc_mutex;
 #[cfg(feature = "std")]
 mod arc_rw_lock;
 
-use core::cmp::Ordering;
+use core::{cmp::Ordering, ops::RangeBounds};
 
 #[cfg(feature = "std")]
 pub use arc_mutex::*;
 #[cfg(feature = "std")]
 pub use arc_rw_lock::*;
+pub use check::*;
+pub use collect::*;
+pub use comparator::*;
+pub use dedup_keep::*;
+pub use entry::*;
+pub use float::TotalOrd;
+pub use inserted_mut::SortedInsertedMut;
+pub use interpolate::*;
+pub use merge::*;
+pub use nan_policy::*;
+pub use order::*;
+#[cfg(feature = "rayon")]
+pub use parallel::*;
+use set_operation::SetOperation;
+pub use set_operation::SetOperationIter;
+#[cfg(feature = "std")]
+pub use sliding_quantile::SlidingQuantile;
+pub use sorted_bag::SortedBag;
+pub use sorted_chunked_vec::SortedChunkedVec;
+pub use sorted_vec_map::SortedVecMap;
+#[cfg(feature = "std")]
+pub use sorted_window::{SortedWindow, WindowInsert, WindowKey};
+#[cfg(feature = "std")]
+pub use stable_scheduler::{ScheduleHandle, StableScheduler};
 
 #[doc(hidden)]
 pub trait SortedInsertBasic<T> {
     #[doc(hidden)]
     fn insert_element(&mut self, index: usize, element: T);
+
+    #[doc(hidden)]
+    fn get_element(&self, index: usize) -> Option<&T>;
 }
 
 pub trait SortedInsertBy<T>: SortedInsertBasic<T> {
@@ -82,6 +213,10 @@ pub trait SortedInsertBy<T>: SortedInsertBasic<T> {
 
         self.insert_element(index, element);
 
+        // `f` may be strict or not, so a pair is out of order only if the second element goes before the first one but not the other way around
+        #[cfg(feature = "debug-checks")]
+        check::check_inserted(self, index, |a, b| f(a, b) || !f(b, a));
+
         index
     }
 
@@ -109,6 +244,20 @@ pub trait SortedInsertByKey<T>: SortedInsertBy<T> {
     ) -> usize {
         self.sorted_insert_by(element, |e, element| f(e) >= f(element))
     }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_desc_by_key(element, f),
+        }
+    }
 }
 
 pub trait SortedInsert<T: Ord>: SortedInsertByKey<T> {
@@ -122,6 +271,15 @@ pub trait SortedInsert<T: Ord>: SortedInsertByKey<T> {
     fn sorted_insert_desc(&mut self, element: T) -> usize {
         self.sorted_insert_desc_by_key(element, |element| element)
     }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_ordered(&mut self, element: T, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc(element),
+            Order::Descending => self.sorted_insert_desc(element),
+        }
+    }
 }
 
 pub trait SortedInsertBinaryBy<T>: SortedInsertBy<T> {
@@ -135,6 +293,9 @@ pub trait SortedInsertBinaryBy<T>: SortedInsertBy<T> {
 
         self.insert_element(index, element);
 
+        #[cfg(feature = "debug-checks")]
+        check::check_inserted(self, index, |a, b| f(a, b) != Ordering::Greater);
+
         index
     }
 
@@ -162,6 +323,20 @@ pub trait SortedInsertBinaryByKey<T>: SortedInsertBinaryBy<T> {
     ) -> usize {
         self.sorted_insert_binary_by(element, |e, element| f(element).cmp(f(e)))
     }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_binary_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_binary_desc_by_key(element, f),
+        }
+    }
 }
 
 pub trait SortedInsertBinary<T: Ord>: SortedInsertBinaryByKey<T> {
@@ -176,4 +351,1570 @@ pub trait SortedInsertBinary<T: Ord>: SortedInsertBinaryByKey<T> {
     fn sorted_insert_desc_binary(&mut self, element: T) -> usize {
         self.sorted_insert_binary_desc_by_key(element, |element| element)
     }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_ordered(&mut self, element: T, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_binary(element),
+            Order::Descending => self.sorted_insert_desc_binary(element),
+        }
+    }
+}
+
+pub trait SortedInsertGallopBy<T>: SortedInsertBy<T> {
+    /// Insert elements to this sorted collection by a specific comparator and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    fn sorted_insert_gallop_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> usize {
+        let index = self.get_sorted_insert_index_gallop_by(|e| f(e, &element));
+
+        self.insert_element(index, element);
+
+        #[cfg(feature = "debug-checks")]
+        check::check_inserted(self, index, |a, b| f(a, b) != Ordering::Greater);
+
+        index
+    }
+
+    #[doc(hidden)]
+    fn get_sorted_insert_index_gallop_by<F: FnMut(&T) -> Ordering>(&mut self, f: F) -> usize;
+}
+
+pub trait SortedInsertGallopByKey<T>: SortedInsertGallopBy<T> {
+    /// Insert elements to this sorted collection in ascending order by a specific key and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    #[inline]
+    fn sorted_insert_gallop_asc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_gallop_by(element, |e, element| f(e).cmp(f(element)))
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    #[inline]
+    fn sorted_insert_gallop_desc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_gallop_by(element, |e, element| f(element).cmp(f(e)))
+    }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_gallop_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_gallop_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_gallop_desc_by_key(element, f),
+        }
+    }
+}
+
+pub trait SortedInsertGallop<T: Ord>: SortedInsertGallopByKey<T> {
+    /// Insert elements to this sorted collection in ascending order and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    #[inline]
+    fn sorted_insert_asc_gallop(&mut self, element: T) -> usize {
+        self.sorted_insert_gallop_asc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted, which costs `O(log d)` comparisons where `d` is the distance between the index and the end.
+    #[inline]
+    fn sorted_insert_desc_gallop(&mut self, element: T) -> usize {
+        self.sorted_insert_gallop_desc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use exponential search from the end to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_gallop_ordered(&mut self, element: T, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_gallop(element),
+            Order::Descending => self.sorted_insert_desc_gallop(element),
+        }
+    }
+}
+
+pub trait SortedInsertWithHintBy<T>: SortedInsertBinaryBy<T> {
+    /// Insert elements to this sorted collection by a specific comparator and return the inserted index. Use exponential search starting from `hint` to find the index where a matching element could be inserted, so the closer `hint` is to that index, the fewer comparisons are needed. The returned index can be used as the hint of the next insertion.
+    #[inline]
+    fn sorted_insert_with_hint_by<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        element: T,
+        hint: usize,
+        mut f: F,
+    ) -> usize {
+        let index = self.get_sorted_insert_index_with_hint_by(hint, |e| f(e, &element));
+
+        self.insert_element(index, element);
+
+        #[cfg(feature = "debug-checks")]
+        check::check_inserted(self, index, |a, b| f(a, b) != Ordering::Greater);
+
+        index
+    }
+
+    #[doc(hidden)]
+    fn get_sorted_insert_index_with_hint_by<F: FnMut(&T) -> Ordering>(
+        &mut self,
+        hint: usize,
+        f: F,
+    ) -> usize;
+}
+
+pub trait SortedInsertWithHintByKey<T>: SortedInsertWithHintBy<T> {
+    /// Insert elements to this sorted collection in ascending order by a specific key and return the inserted index. Use exponential search starting from `hint` to find the index where a matching element could be inserted, so the closer `hint` is to that index, the fewer comparisons are needed. The returned index can be used as the hint of the next insertion.
+    #[inline]
+    fn sorted_insert_with_hint_asc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        hint: usize,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_with_hint_by(element, hint, |e, element| f(e).cmp(f(element)))
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key and return the inserted index. Use exponential search starting from `hint` to find the index where a matching element could be inserted, so the closer `hint` is to that index, the fewer comparisons are needed. The returned index can be used as the hint of the next insertion.
+    #[inline]
+    fn sorted_insert_with_hint_desc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        hint: usize,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_with_hint_by(element, hint, |e, element| f(element).cmp(f(e)))
+    }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use exponential search starting from `hint` to find the index where a matching element could be inserted, so the closer `hint` is to that index, the fewer comparisons are needed. The returned index can be used as the hint of the next insertion.
+    #[inline]
+    fn sorted_insert_with_hint_by_key_ordered<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        hint: usize,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_with_hint_asc_by_key(element, hint, f),
+            Order::Descending => self.sorted_insert_with_hint_desc_by_key(element, hint, f),
+        }
+    }
+}
+
+pub trait SortedInsertWithHint<T: Ord>: SortedInsertWithHintByKey<T> {
+    /// Insert elements to this sorted collection in ascending order and return the inserted index. Use exponential search starting from `hint` to find the index where a matching element could be inserted, so the closer `hint` is to that index, the fewer comparisons are needed. The returned index can be used as the hint of the next insertion.
+    #[inline]
+    fn sorted_insert_asc_with_hint(&mut self, element: T, hint: usize) -> usize {
+        self.sorted_insert_with_hint_asc_by_key(element, hint, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order and return the inserted index. Use exponential search starting from `hint` to find the index where a matching element could be inserted, so the closer `hint` is to that index, the fewer comparisons are needed. The returned index can be used as the hint of the next insertion.
+    #[inline]
+    fn sorted_insert_desc_with_hint(&mut self, element: T, hint: usize) -> usize {
+        self.sorted_insert_with_hint_desc_by_key(element, hint, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use exponential search starting from `hint` to find the index where a matching element could be inserted, so the closer `hint` is to that index, the fewer comparisons are needed. The returned index can be used as the hint of the next insertion.
+    #[inline]
+    fn sorted_insert_with_hint_ordered(&mut self, element: T, hint: usize, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_with_hint(element, hint),
+            Order::Descending => self.sorted_insert_desc_with_hint(element, hint),
+        }
+    }
+}
+
+pub trait SortedInsertInterpolationByKey<T>: SortedInsertBy<T> {
+    /// Insert elements to this sorted collection in ascending order by a specific key and return the inserted index. Use interpolation search, falling back to binary search if the keys turn out not to be uniformly distributed, to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_interpolation_asc_by_key<A: InterpolateKey, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> usize {
+        let index = self.get_sorted_insert_index_interpolation_by_key(f(&element), &mut f, false);
+
+        self.insert_element(index, element);
+
+        #[cfg(feature = "debug-checks")]
+        check::check_inserted(self, index, |a, b| f(a).compare(f(b)) != Ordering::Greater);
+
+        index
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key and return the inserted index. Use interpolation search, falling back to binary search if the keys turn out not to be uniformly distributed, to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_interpolation_desc_by_key<A: InterpolateKey, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> usize {
+        let index = self.get_sorted_insert_index_interpolation_by_key(f(&element), &mut f, true);
+
+        self.insert_element(index, element);
+
+        #[cfg(feature = "debug-checks")]
+        check::check_inserted(self, index, |a, b| f(b).compare(f(a)) != Ordering::Greater);
+
+        index
+    }
+
+    /// Insert elements to this sorted collection in the given order by a specific key and return the inserted index. Use interpolation search, falling back to binary search if the keys turn out not to be uniformly distributed, to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_interpolation_by_key_ordered<A: InterpolateKey, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_interpolation_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_interpolation_desc_by_key(element, f),
+        }
+    }
+
+    #[doc(hidden)]
+    fn get_sorted_insert_index_interpolation_by_key<A: InterpolateKey, F: FnMut(&T) -> &A>(
+        &mut self,
+        key: &A,
+        f: F,
+        descending: bool,
+    ) -> usize;
+}
+
+pub trait SortedInsertInterpolation<T: InterpolateKey>: SortedInsertInterpolationByKey<T> {
+    /// Insert elements to this sorted collection in ascending order and return the inserted index. Use interpolation search, falling back to binary search if the elements turn out not to be uniformly distributed, to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_asc_interpolation(&mut self, element: T) -> usize {
+        self.sorted_insert_interpolation_asc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order and return the inserted index. Use interpolation search, falling back to binary search if the elements turn out not to be uniformly distributed, to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_desc_interpolation(&mut self, element: T) -> usize {
+        self.sorted_insert_interpolation_desc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in the given order and return the inserted index. Use interpolation search, falling back to binary search if the elements turn out not to be uniformly distributed, to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_interpolation_ordered(&mut self, element: T, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_asc_interpolation(element),
+            Order::Descending => self.sorted_insert_desc_interpolation(element),
+        }
+    }
+}
+
+pub trait SortedInsertTotalByKey<T>: SortedInsertBinaryBy<T> {
+    /// Insert elements to this sorted collection in ascending order by a specific key compared by its total order and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_total_asc_by_key<A: TotalOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_by(element, |e, element| f(e).total_cmp(f(element)) != Ordering::Greater)
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key compared by its total order and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_total_desc_by_key<A: TotalOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_by(element, |e, element| f(e).total_cmp(f(element)) != Ordering::Less)
+    }
+
+    /// Insert elements to this sorted collection in ascending order by a specific key compared by its total order and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_total_asc_by_key<A: TotalOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_binary_by(element, |e, element| f(e).total_cmp(f(element)))
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key compared by its total order and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_total_desc_by_key<A: TotalOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> usize {
+        self.sorted_insert_binary_by(element, |e, element| f(element).total_cmp(f(e)))
+    }
+
+    /// Insert elements to this sorted collection in the given order by a specific key compared by its total order and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_total_by_key_ordered<A: TotalOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_total_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_total_desc_by_key(element, f),
+        }
+    }
+
+    /// Insert elements to this sorted collection in the given order by a specific key compared by its total order and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_total_by_key_ordered<A: TotalOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        f: F,
+        order: Order,
+    ) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_binary_total_asc_by_key(element, f),
+            Order::Descending => self.sorted_insert_binary_total_desc_by_key(element, f),
+        }
+    }
+}
+
+pub trait SortedInsertTotal<T: TotalOrd>: SortedInsertTotalByKey<T> {
+    /// Insert elements to this sorted collection in ascending order by their total order and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_total_asc(&mut self, element: T) -> usize {
+        self.sorted_insert_total_asc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order by their total order and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_total_desc(&mut self, element: T) -> usize {
+        self.sorted_insert_total_desc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in ascending order by their total order and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_total_asc_binary(&mut self, element: T) -> usize {
+        self.sorted_insert_binary_total_asc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order by their total order and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_total_desc_binary(&mut self, element: T) -> usize {
+        self.sorted_insert_binary_total_desc_by_key(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in the given order by their total order and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_total_ordered(&mut self, element: T, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_total_asc(element),
+            Order::Descending => self.sorted_insert_total_desc(element),
+        }
+    }
+
+    /// Insert elements to this sorted collection in the given order by their total order and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_total_binary_ordered(&mut self, element: T, order: Order) -> usize {
+        match order {
+            Order::Ascending => self.sorted_insert_total_asc_binary(element),
+            Order::Descending => self.sorted_insert_total_desc_binary(element),
+        }
+    }
+}
+
+pub trait SortedInsertPartialByKey<T>: SortedInsertBinaryBy<T> {
+    /// Insert elements to this sorted collection in ascending order by a specific key which is only `PartialOrd` and return the inserted index, or give the element back if its key is rejected by the `NanPolicy`. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_partial_asc_by_key<A: PartialOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+        policy: NanPolicy,
+    ) -> Result<usize, T> {
+        if policy.rejects(f(&element)) {
+            return Err(element);
+        }
+
+        Ok(self.sorted_insert_by(element, |e, element| {
+            policy.compare(f(e), f(element), false) != Ordering::Greater
+        }))
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key which is only `PartialOrd` and return the inserted index, or give the element back if its key is rejected by the `NanPolicy`. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_partial_desc_by_key<A: PartialOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+        policy: NanPolicy,
+    ) -> Result<usize, T> {
+        if policy.rejects(f(&element)) {
+            return Err(element);
+        }
+
+        Ok(self.sorted_insert_by(element, |e, element| {
+            policy.compare(f(e), f(element), true) != Ordering::Greater
+        }))
+    }
+
+    /// Insert elements to this sorted collection in ascending order by a specific key which is only `PartialOrd` and return the inserted index, or give the element back if its key is rejected by the `NanPolicy`. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_partial_asc_by_key<A: PartialOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+        policy: NanPolicy,
+    ) -> Result<usize, T> {
+        if policy.rejects(f(&element)) {
+            return Err(element);
+        }
+
+        Ok(self
+            .sorted_insert_binary_by(element, |e, element| policy.compare(f(e), f(element), false)))
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key which is only `PartialOrd` and return the inserted index, or give the element back if its key is rejected by the `NanPolicy`. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_partial_desc_by_key<A: PartialOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+        policy: NanPolicy,
+    ) -> Result<usize, T> {
+        if policy.rejects(f(&element)) {
+            return Err(element);
+        }
+
+        Ok(self
+            .sorted_insert_binary_by(element, |e, element| policy.compare(f(e), f(element), true)))
+    }
+
+    /// Insert elements to this sorted collection in the given order by a specific key which is only `PartialOrd` and return the inserted index, or give the element back if its key is rejected by the `NanPolicy`. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_partial_by_key_ordered<A: PartialOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        f: F,
+        policy: NanPolicy,
+        order: Order,
+    ) -> Result<usize, T> {
+        match order {
+            Order::Ascending => self.sorted_insert_partial_asc_by_key(element, f, policy),
+            Order::Descending => self.sorted_insert_partial_desc_by_key(element, f, policy),
+        }
+    }
+
+    /// Insert elements to this sorted collection in the given order by a specific key which is only `PartialOrd` and return the inserted index, or give the element back if its key is rejected by the `NanPolicy`. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_partial_by_key_ordered<A: PartialOrd, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        f: F,
+        policy: NanPolicy,
+        order: Order,
+    ) -> Result<usize, T> {
+        match order {
+            Order::Ascending => self.sorted_insert_binary_partial_asc_by_key(element, f, policy),
+            Order::Descending => self.sorted_insert_binary_partial_desc_by_key(element, f, policy),
+        }
+    }
+}
+
+pub trait SortedInsertPartial<T: PartialOrd>: SortedInsertPartialByKey<T> {
+    /// Insert elements to this sorted collection in ascending order by `PartialOrd` and return the inserted index, or give the element back if it is rejected by the `NanPolicy`. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_partial_asc(&mut self, element: T, policy: NanPolicy) -> Result<usize, T> {
+        self.sorted_insert_partial_asc_by_key(element, |element| element, policy)
+    }
+
+    /// Insert elements to this sorted collection in descending order by `PartialOrd` and return the inserted index, or give the element back if it is rejected by the `NanPolicy`. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_partial_desc(&mut self, element: T, policy: NanPolicy) -> Result<usize, T> {
+        self.sorted_insert_partial_desc_by_key(element, |element| element, policy)
+    }
+
+    /// Insert elements to this sorted collection in ascending order by `PartialOrd` and return the inserted index, or give the element back if it is rejected by the `NanPolicy`. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_partial_asc_binary(
+        &mut self,
+        element: T,
+        policy: NanPolicy,
+    ) -> Result<usize, T> {
+        self.sorted_insert_binary_partial_asc_by_key(element, |element| element, policy)
+    }
+
+    /// Insert elements to this sorted collection in descending order by `PartialOrd` and return the inserted index, or give the element back if it is rejected by the `NanPolicy`. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_partial_desc_binary(
+        &mut self,
+        element: T,
+        policy: NanPolicy,
+    ) -> Result<usize, T> {
+        self.sorted_insert_binary_partial_desc_by_key(element, |element| element, policy)
+    }
+
+    /// Insert elements to this sorted collection in the given order by `PartialOrd` and return the inserted index, or give the element back if it is rejected by the `NanPolicy`. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_partial_ordered(
+        &mut self,
+        element: T,
+        policy: NanPolicy,
+        order: Order,
+    ) -> Result<usize, T> {
+        match order {
+            Order::Ascending => self.sorted_insert_partial_asc(element, policy),
+            Order::Descending => self.sorted_insert_partial_desc(element, policy),
+        }
+    }
+
+    /// Insert elements to this sorted collection in the given order by `PartialOrd` and return the inserted index, or give the element back if it is rejected by the `NanPolicy`. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_partial_binary_ordered(
+        &mut self,
+        element: T,
+        policy: NanPolicy,
+        order: Order,
+    ) -> Result<usize, T> {
+        match order {
+            Order::Ascending => self.sorted_insert_partial_asc_binary(element, policy),
+            Order::Descending => self.sorted_insert_partial_desc_binary(element, policy),
+        }
+    }
+}
+
+pub trait SortedInsertComparator<T>: SortedInsertBinaryBy<T> {
+    /// Insert elements to this sorted collection by a specific `Comparator` and return the inserted index. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_by_comparator<C: Comparator<T>>(
+        &mut self,
+        element: T,
+        comparator: &C,
+    ) -> usize {
+        self.sorted_insert_by(element, |e, element| {
+            comparator.compare(e, element) != Ordering::Greater
+        })
+    }
+
+    /// Insert elements to this sorted collection by a specific `Comparator` and return the inserted index. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_by_comparator<C: Comparator<T>>(
+        &mut self,
+        element: T,
+        comparator: &C,
+    ) -> usize {
+        self.sorted_insert_binary_by(element, |e, element| comparator.compare(e, element))
+    }
+
+    /// Search this sorted collection for an element equal to `element` by a specific `Comparator` with binary search. Return `Ok` with the index of a matching element, or `Err` with the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_search_by_comparator<C: Comparator<T>>(
+        &self,
+        element: &T,
+        comparator: &C,
+    ) -> Result<usize, usize> {
+        self.sorted_binary_search_by(|e| comparator.compare(e, element))
+    }
+
+    #[doc(hidden)]
+    fn sorted_binary_search_by<F: FnMut(&T) -> Ordering>(&self, f: F) -> Result<usize, usize>;
+}
+
+pub trait SortedInsertMutBy<T>: SortedInsertBinaryBy<T> {
+    /// Insert elements to this sorted collection by a specific comparator and return a mutable handle to the inserted element. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_by_mut<F: FnMut(&T, &T) -> bool>(
+        &mut self,
+        element: T,
+        f: F,
+    ) -> SortedInsertedMut<'_, T> {
+        let index = self.sorted_insert_by(element, f);
+
+        SortedInsertedMut::new(index, self.split_neighbors_mut(index))
+    }
+
+    /// Insert elements to this sorted collection by a specific comparator and return a mutable handle to the inserted element. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_by_mut<F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        element: T,
+        f: F,
+    ) -> SortedInsertedMut<'_, T> {
+        let index = self.sorted_insert_binary_by(element, f);
+
+        SortedInsertedMut::new(index, self.split_neighbors_mut(index))
+    }
+
+    #[doc(hidden)]
+    fn split_neighbors_mut(&mut self, index: usize) -> (Option<&T>, &mut T, Option<&T>);
+}
+
+pub trait SortedInsertMutByKey<T>: SortedInsertMutBy<T> {
+    /// Insert elements to this sorted collection in ascending order by a specific key and return a mutable handle to the inserted element. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_asc_by_key_mut<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> SortedInsertedMut<'_, T> {
+        self.sorted_insert_by_mut(element, |e, element| f(e) <= f(element))
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key and return a mutable handle to the inserted element. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_desc_by_key_mut<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> SortedInsertedMut<'_, T> {
+        self.sorted_insert_by_mut(element, |e, element| f(e) >= f(element))
+    }
+
+    /// Insert elements to this sorted collection in ascending order by a specific key and return a mutable handle to the inserted element. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_asc_by_key_mut<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> SortedInsertedMut<'_, T> {
+        self.sorted_insert_binary_by_mut(element, |e, element| f(e).cmp(f(element)))
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key and return a mutable handle to the inserted element. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_binary_desc_by_key_mut<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        element: T,
+        mut f: F,
+    ) -> SortedInsertedMut<'_, T> {
+        self.sorted_insert_binary_by_mut(element, |e, element| f(element).cmp(f(e)))
+    }
+}
+
+pub trait SortedInsertMut<T: Ord>: SortedInsertMutByKey<T> {
+    /// Insert elements to this sorted collection in ascending order and return a mutable handle to the inserted element. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_asc_mut(&mut self, element: T) -> SortedInsertedMut<'_, T> {
+        self.sorted_insert_asc_by_key_mut(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order and return a mutable handle to the inserted element. Use linear search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_desc_mut(&mut self, element: T) -> SortedInsertedMut<'_, T> {
+        self.sorted_insert_desc_by_key_mut(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in ascending order and return a mutable handle to the inserted element. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_asc_binary_mut(&mut self, element: T) -> SortedInsertedMut<'_, T> {
+        self.sorted_insert_binary_asc_by_key_mut(element, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order and return a mutable handle to the inserted element. Use binary search to find the index where a matching element could be inserted.
+    #[inline]
+    fn sorted_insert_desc_binary_mut(&mut self, element: T) -> SortedInsertedMut<'_, T> {
+        self.sorted_insert_binary_desc_by_key_mut(element, |element| element)
+    }
+}
+
+pub trait SortedEntryBy<T>: SortedInsertMutBy<T> {
+    /// Find the entry of an element with a specific key in this sorted collection with binary search. `f` compares an element with the key, as `binary_search_by` of a slice does. If several elements have the key, any of them may be found.
+    #[inline]
+    fn sorted_entry_by<'a, F: FnMut(&T) -> Ordering + 'a>(
+        &'a mut self,
+        f: F,
+    ) -> SortedEntry<'a, Self, T> {
+        entry::find(self, f)
+    }
+}
+
+pub trait SortedEntryByKey<T>: SortedEntryBy<T> {
+    /// Find the entry of an element with a specific key in this collection sorted in ascending order by the key, with binary search.
+    #[inline]
+    fn sorted_entry_asc_by_key<'a, A: Ord + ?Sized, F: FnMut(&T) -> &A + 'a>(
+        &'a mut self,
+        key: &'a A,
+        mut f: F,
+    ) -> SortedEntry<'a, Self, T> {
+        entry::find(self, move |e| f(e).cmp(key))
+    }
+
+    /// Find the entry of an element with a specific key in this collection sorted in descending order by the key, with binary search.
+    #[inline]
+    fn sorted_entry_desc_by_key<'a, A: Ord + ?Sized, F: FnMut(&T) -> &A + 'a>(
+        &'a mut self,
+        key: &'a A,
+        mut f: F,
+    ) -> SortedEntry<'a, Self, T> {
+        entry::find(self, move |e| key.cmp(f(e)))
+    }
+}
+
+pub trait SortedMergeBy<T> {
+    /// Merge another collection sorted by a specific comparator into this collection sorted by the same comparator. The elements of this collection are kept before the equal elements of `other`.
+    #[inline]
+    fn sorted_merge_by<I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: I,
+        f: F,
+    ) {
+        self.merge_elements_by(other, false, f)
+    }
+
+    /// Merge another collection sorted by a specific comparator into this collection sorted by the same comparator, and keep only the first one of consecutive equal elements, so the elements of this collection win over the equal elements of `other`. Equal elements which are already in this collection are collapsed as well.
+    #[inline]
+    fn sorted_merge_dedup_by<I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: I,
+        f: F,
+    ) {
+        self.merge_elements_by(other, true, f)
+    }
+
+    #[doc(hidden)]
+    fn merge_elements_by<I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: I,
+        dedup: bool,
+        f: F,
+    );
+}
+
+pub trait SortedMergeByKey<T>: SortedMergeBy<T> {
+    /// Merge another collection sorted in ascending order by a specific key into this collection sorted in the same order. The elements of this collection are kept before the elements of `other` with equal keys.
+    #[inline]
+    fn sorted_merge_asc_by_key<I: IntoIterator<Item = T>, A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_merge_by(other, |a, b| f(a).cmp(f(b)))
+    }
+
+    /// Merge another collection sorted in descending order by a specific key into this collection sorted in the same order. The elements of this collection are kept before the elements of `other` with equal keys.
+    #[inline]
+    fn sorted_merge_desc_by_key<I: IntoIterator<Item = T>, A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_merge_by(other, |a, b| f(b).cmp(f(a)))
+    }
+
+    /// Merge another collection sorted in ascending order by a specific key into this collection sorted in the same order, and keep only the first one of consecutive elements with equal keys.
+    #[inline]
+    fn sorted_merge_dedup_asc_by_key<I: IntoIterator<Item = T>, A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_merge_dedup_by(other, |a, b| f(a).cmp(f(b)))
+    }
+
+    /// Merge another collection sorted in descending order by a specific key into this collection sorted in the same order, and keep only the first one of consecutive elements with equal keys.
+    #[inline]
+    fn sorted_merge_dedup_desc_by_key<I: IntoIterator<Item = T>, A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_merge_dedup_by(other, |a, b| f(b).cmp(f(a)))
+    }
+}
+
+pub trait SortedMerge<T: Ord>: SortedMergeByKey<T> {
+    /// Merge another collection sorted in ascending order into this collection sorted in the same order. The elements of this collection are kept before the equal elements of `other`.
+    #[inline]
+    fn sorted_merge_asc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_merge_asc_by_key(other, |element| element)
+    }
+
+    /// Merge another collection sorted in descending order into this collection sorted in the same order. The elements of this collection are kept before the equal elements of `other`.
+    #[inline]
+    fn sorted_merge_desc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_merge_desc_by_key(other, |element| element)
+    }
+
+    /// Merge another collection sorted in ascending order into this collection sorted in the same order, and keep only the first one of consecutive equal elements.
+    #[inline]
+    fn sorted_merge_dedup_asc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_merge_dedup_asc_by_key(other, |element| element)
+    }
+
+    /// Merge another collection sorted in descending order into this collection sorted in the same order, and keep only the first one of consecutive equal elements.
+    #[inline]
+    fn sorted_merge_dedup_desc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_merge_dedup_desc_by_key(other, |element| element)
+    }
+}
+
+pub trait SortedSetOperationBy<T> {
+    /// Iterate over the union of this sorted collection and `other`, both sorted by a specific `Comparator`, in linear time. Yield the elements which are in either collection. An element which is in both collections is yielded from this collection, as many times as the larger count in the two collections.
+    #[inline]
+    fn sorted_union_by<'a, C: Comparator<T>>(
+        &'a self,
+        other: &'a Self,
+        comparator: C,
+    ) -> SetOperationIter<'a, T, C> {
+        SetOperationIter::new(
+            self.as_sorted_slices(),
+            other.as_sorted_slices(),
+            SetOperation::Union,
+            comparator,
+        )
+    }
+
+    /// Iterate over the intersection of this sorted collection and `other`, both sorted by a specific `Comparator`, in linear time. Yield the elements which are in both collections, taken from this collection, as many times as the smaller count in the two collections.
+    #[inline]
+    fn sorted_intersection_by<'a, C: Comparator<T>>(
+        &'a self,
+        other: &'a Self,
+        comparator: C,
+    ) -> SetOperationIter<'a, T, C> {
+        SetOperationIter::new(
+            self.as_sorted_slices(),
+            other.as_sorted_slices(),
+            SetOperation::Intersection,
+            comparator,
+        )
+    }
+
+    /// Iterate over the difference of this sorted collection and `other`, both sorted by a specific `Comparator`, in linear time. Yield the elements which are in this collection but not in `other`. An element which is in both collections is yielded as many times as its count in this collection exceeds its count in `other`.
+    #[inline]
+    fn sorted_difference_by<'a, C: Comparator<T>>(
+        &'a self,
+        other: &'a Self,
+        comparator: C,
+    ) -> SetOperationIter<'a, T, C> {
+        SetOperationIter::new(
+            self.as_sorted_slices(),
+            other.as_sorted_slices(),
+            SetOperation::Difference,
+            comparator,
+        )
+    }
+
+    /// Iterate over the symmetric difference of this sorted collection and `other`, both sorted by a specific `Comparator`, in linear time. Yield the elements which are in exactly one of the collections. An element which is in both collections is yielded as many times as the difference between its counts, from the collection which has more.
+    #[inline]
+    fn sorted_symmetric_difference_by<'a, C: Comparator<T>>(
+        &'a self,
+        other: &'a Self,
+        comparator: C,
+    ) -> SetOperationIter<'a, T, C> {
+        SetOperationIter::new(
+            self.as_sorted_slices(),
+            other.as_sorted_slices(),
+            SetOperation::SymmetricDifference,
+            comparator,
+        )
+    }
+
+    #[doc(hidden)]
+    fn as_sorted_slices(&self) -> (&[T], &[T]);
+}
+
+pub trait SortedSetOperationByKey<T>: SortedSetOperationBy<T> {
+    /// Iterate over the union of this collection and `other`, both sorted in ascending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_union_asc_by_key<'a, A: Ord, F: Fn(&T) -> &A>(
+        &'a self,
+        other: &'a Self,
+        f: F,
+    ) -> SetOperationIter<'a, T, ByKey<Natural<A>, F, A>> {
+        self.sorted_union_by(other, Natural::new().by_key(f))
+    }
+
+    /// Iterate over the union of this collection and `other`, both sorted in descending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_union_desc_by_key<'a, A: Ord, F: Fn(&T) -> &A>(
+        &'a self,
+        other: &'a Self,
+        f: F,
+    ) -> SetOperationIter<'a, T, ByKey<Reversed<Natural<A>>, F, A>> {
+        self.sorted_union_by(other, Natural::new().reverse().by_key(f))
+    }
+
+    /// Iterate over the intersection of this collection and `other`, both sorted in ascending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_intersection_asc_by_key<'a, A: Ord, F: Fn(&T) -> &A>(
+        &'a self,
+        other: &'a Self,
+        f: F,
+    ) -> SetOperationIter<'a, T, ByKey<Natural<A>, F, A>> {
+        self.sorted_intersection_by(other, Natural::new().by_key(f))
+    }
+
+    /// Iterate over the intersection of this collection and `other`, both sorted in descending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_intersection_desc_by_key<'a, A: Ord, F: Fn(&T) -> &A>(
+        &'a self,
+        other: &'a Self,
+        f: F,
+    ) -> SetOperationIter<'a, T, ByKey<Reversed<Natural<A>>, F, A>> {
+        self.sorted_intersection_by(other, Natural::new().reverse().by_key(f))
+    }
+
+    /// Iterate over the difference of this collection and `other`, both sorted in ascending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_difference_asc_by_key<'a, A: Ord, F: Fn(&T) -> &A>(
+        &'a self,
+        other: &'a Self,
+        f: F,
+    ) -> SetOperationIter<'a, T, ByKey<Natural<A>, F, A>> {
+        self.sorted_difference_by(other, Natural::new().by_key(f))
+    }
+
+    /// Iterate over the difference of this collection and `other`, both sorted in descending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_difference_desc_by_key<'a, A: Ord, F: Fn(&T) -> &A>(
+        &'a self,
+        other: &'a Self,
+        f: F,
+    ) -> SetOperationIter<'a, T, ByKey<Reversed<Natural<A>>, F, A>> {
+        self.sorted_difference_by(other, Natural::new().reverse().by_key(f))
+    }
+
+    /// Iterate over the symmetric difference of this collection and `other`, both sorted in ascending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_symmetric_difference_asc_by_key<'a, A: Ord, F: Fn(&T) -> &A>(
+        &'a self,
+        other: &'a Self,
+        f: F,
+    ) -> SetOperationIter<'a, T, ByKey<Natural<A>, F, A>> {
+        self.sorted_symmetric_difference_by(other, Natural::new().by_key(f))
+    }
+
+    /// Iterate over the symmetric difference of this collection and `other`, both sorted in descending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_symmetric_difference_desc_by_key<'a, A: Ord, F: Fn(&T) -> &A>(
+        &'a self,
+        other: &'a Self,
+        f: F,
+    ) -> SetOperationIter<'a, T, ByKey<Reversed<Natural<A>>, F, A>> {
+        self.sorted_symmetric_difference_by(other, Natural::new().reverse().by_key(f))
+    }
+}
+
+pub trait SortedSetOperation<T: Ord>: SortedSetOperationByKey<T> {
+    /// Iterate over the union of this collection and `other`, both sorted in ascending order, in linear time.
+    #[inline]
+    fn sorted_union_asc<'a>(&'a self, other: &'a Self) -> SetOperationIter<'a, T, Natural<T>> {
+        self.sorted_union_by(other, Natural::new())
+    }
+
+    /// Iterate over the union of this collection and `other`, both sorted in descending order, in linear time.
+    #[inline]
+    fn sorted_union_desc<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> SetOperationIter<'a, T, Reversed<Natural<T>>> {
+        self.sorted_union_by(other, Natural::new().reverse())
+    }
+
+    /// Iterate over the intersection of this collection and `other`, both sorted in ascending order, in linear time.
+    #[inline]
+    fn sorted_intersection_asc<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> SetOperationIter<'a, T, Natural<T>> {
+        self.sorted_intersection_by(other, Natural::new())
+    }
+
+    /// Iterate over the intersection of this collection and `other`, both sorted in descending order, in linear time.
+    #[inline]
+    fn sorted_intersection_desc<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> SetOperationIter<'a, T, Reversed<Natural<T>>> {
+        self.sorted_intersection_by(other, Natural::new().reverse())
+    }
+
+    /// Iterate over the difference of this collection and `other`, both sorted in ascending order, in linear time.
+    #[inline]
+    fn sorted_difference_asc<'a>(&'a self, other: &'a Self) -> SetOperationIter<'a, T, Natural<T>> {
+        self.sorted_difference_by(other, Natural::new())
+    }
+
+    /// Iterate over the difference of this collection and `other`, both sorted in descending order, in linear time.
+    #[inline]
+    fn sorted_difference_desc<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> SetOperationIter<'a, T, Reversed<Natural<T>>> {
+        self.sorted_difference_by(other, Natural::new().reverse())
+    }
+
+    /// Iterate over the symmetric difference of this collection and `other`, both sorted in ascending order, in linear time.
+    #[inline]
+    fn sorted_symmetric_difference_asc<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> SetOperationIter<'a, T, Natural<T>> {
+        self.sorted_symmetric_difference_by(other, Natural::new())
+    }
+
+    /// Iterate over the symmetric difference of this collection and `other`, both sorted in descending order, in linear time.
+    #[inline]
+    fn sorted_symmetric_difference_desc<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> SetOperationIter<'a, T, Reversed<Natural<T>>> {
+        self.sorted_symmetric_difference_by(other, Natural::new().reverse())
+    }
+}
+
+pub trait SortedSetOperationInPlaceBy<T> {
+    /// Replace this collection with the union of this collection and `other`, both sorted by a specific comparator, in linear time. Keep the elements which are in either collection. An element which is in both collections is kept from this collection, as many times as the larger count in the two collections.
+    #[inline]
+    fn sorted_union_in_place_by<I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: I,
+        f: F,
+    ) {
+        self.with_elements_vec(|v| set_operation::apply_vec_by(v, other, SetOperation::Union, f))
+    }
+
+    /// Replace this collection with the intersection of this collection and `other`, both sorted by a specific comparator, in linear time. Keep the elements which are in both collections, taken from this collection, as many times as the smaller count in the two collections.
+    #[inline]
+    fn sorted_intersection_in_place_by<I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: I,
+        f: F,
+    ) {
+        self.with_elements_vec(|v| {
+            set_operation::apply_vec_by(v, other, SetOperation::Intersection, f)
+        })
+    }
+
+    /// Replace this collection with the difference of this collection and `other`, both sorted by a specific comparator, in linear time. Keep the elements which are in this collection but not in `other`. An element which is in both collections is kept as many times as its count in this collection exceeds its count in `other`.
+    #[inline]
+    fn sorted_difference_in_place_by<I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+        &mut self,
+        other: I,
+        f: F,
+    ) {
+        self.with_elements_vec(|v| {
+            set_operation::apply_vec_by(v, other, SetOperation::Difference, f)
+        })
+    }
+
+    /// Replace this collection with the symmetric difference of this collection and `other`, both sorted by a specific comparator, in linear time. Keep the elements which are in exactly one of the collections. An element which is in both collections is kept as many times as the difference between its counts, from the collection which has more.
+    #[inline]
+    fn sorted_symmetric_difference_in_place_by<
+        I: IntoIterator<Item = T>,
+        F: FnMut(&T, &T) -> Ordering,
+    >(
+        &mut self,
+        other: I,
+        f: F,
+    ) {
+        self.with_elements_vec(|v| {
+            set_operation::apply_vec_by(v, other, SetOperation::SymmetricDifference, f)
+        })
+    }
+
+    #[doc(hidden)]
+    fn with_elements_vec<R, G: FnOnce(&mut alloc::vec::Vec<T>) -> R>(&mut self, g: G) -> R;
+}
+
+pub trait SortedSetOperationInPlaceByKey<T>: SortedSetOperationInPlaceBy<T> {
+    /// Replace this collection with the union of this collection and `other`, both sorted in ascending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_union_in_place_asc_by_key<I: IntoIterator<Item = T>, A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_union_in_place_by(other, |a, b| f(a).cmp(f(b)))
+    }
+
+    /// Replace this collection with the union of this collection and `other`, both sorted in descending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_union_in_place_desc_by_key<I: IntoIterator<Item = T>, A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_union_in_place_by(other, |a, b| f(b).cmp(f(a)))
+    }
+
+    /// Replace this collection with the intersection of this collection and `other`, both sorted in ascending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_intersection_in_place_asc_by_key<
+        I: IntoIterator<Item = T>,
+        A: Ord,
+        F: FnMut(&T) -> &A,
+    >(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_intersection_in_place_by(other, |a, b| f(a).cmp(f(b)))
+    }
+
+    /// Replace this collection with the intersection of this collection and `other`, both sorted in descending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_intersection_in_place_desc_by_key<
+        I: IntoIterator<Item = T>,
+        A: Ord,
+        F: FnMut(&T) -> &A,
+    >(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_intersection_in_place_by(other, |a, b| f(b).cmp(f(a)))
+    }
+
+    /// Replace this collection with the difference of this collection and `other`, both sorted in ascending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_difference_in_place_asc_by_key<
+        I: IntoIterator<Item = T>,
+        A: Ord,
+        F: FnMut(&T) -> &A,
+    >(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_difference_in_place_by(other, |a, b| f(a).cmp(f(b)))
+    }
+
+    /// Replace this collection with the difference of this collection and `other`, both sorted in descending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_difference_in_place_desc_by_key<
+        I: IntoIterator<Item = T>,
+        A: Ord,
+        F: FnMut(&T) -> &A,
+    >(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_difference_in_place_by(other, |a, b| f(b).cmp(f(a)))
+    }
+
+    /// Replace this collection with the symmetric difference of this collection and `other`, both sorted in ascending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_symmetric_difference_in_place_asc_by_key<
+        I: IntoIterator<Item = T>,
+        A: Ord,
+        F: FnMut(&T) -> &A,
+    >(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_symmetric_difference_in_place_by(other, |a, b| f(a).cmp(f(b)))
+    }
+
+    /// Replace this collection with the symmetric difference of this collection and `other`, both sorted in descending order by a specific key, in linear time.
+    #[inline]
+    fn sorted_symmetric_difference_in_place_desc_by_key<
+        I: IntoIterator<Item = T>,
+        A: Ord,
+        F: FnMut(&T) -> &A,
+    >(
+        &mut self,
+        other: I,
+        mut f: F,
+    ) {
+        self.sorted_symmetric_difference_in_place_by(other, |a, b| f(b).cmp(f(a)))
+    }
+}
+
+pub trait SortedSetOperationInPlace<T: Ord>: SortedSetOperationInPlaceByKey<T> {
+    /// Replace this collection with the union of this collection and `other`, both sorted in ascending order, in linear time.
+    #[inline]
+    fn sorted_union_in_place_asc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_union_in_place_asc_by_key(other, |element| element)
+    }
+
+    /// Replace this collection with the union of this collection and `other`, both sorted in descending order, in linear time.
+    #[inline]
+    fn sorted_union_in_place_desc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_union_in_place_desc_by_key(other, |element| element)
+    }
+
+    /// Replace this collection with the intersection of this collection and `other`, both sorted in ascending order, in linear time.
+    #[inline]
+    fn sorted_intersection_in_place_asc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_intersection_in_place_asc_by_key(other, |element| element)
+    }
+
+    /// Replace this collection with the intersection of this collection and `other`, both sorted in descending order, in linear time.
+    #[inline]
+    fn sorted_intersection_in_place_desc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_intersection_in_place_desc_by_key(other, |element| element)
+    }
+
+    /// Replace this collection with the difference of this collection and `other`, both sorted in ascending order, in linear time.
+    #[inline]
+    fn sorted_difference_in_place_asc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_difference_in_place_asc_by_key(other, |element| element)
+    }
+
+    /// Replace this collection with the difference of this collection and `other`, both sorted in descending order, in linear time.
+    #[inline]
+    fn sorted_difference_in_place_desc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_difference_in_place_desc_by_key(other, |element| element)
+    }
+
+    /// Replace this collection with the symmetric difference of this collection and `other`, both sorted in ascending order, in linear time.
+    #[inline]
+    fn sorted_symmetric_difference_in_place_asc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_symmetric_difference_in_place_asc_by_key(other, |element| element)
+    }
+
+    /// Replace this collection with the symmetric difference of this collection and `other`, both sorted in descending order, in linear time.
+    #[inline]
+    fn sorted_symmetric_difference_in_place_desc<I: IntoIterator<Item = T>>(&mut self, other: I) {
+        self.sorted_symmetric_difference_in_place_desc_by_key(other, |element| element)
+    }
+}
+
+pub trait SortedBulkBy<T>: Sized {
+    /// Remove the elements in a range from this sorted collection and return them. `f` compares an element with the range, returning `Less` if the element is before the range, `Equal` if it is in the range and `Greater` if it is after the range. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_drain_range_by<F: FnMut(&T) -> Ordering>(&mut self, mut f: F) -> Self {
+        let start = self.partition_point_elements(|e| f(e) == Ordering::Less);
+        let end = self.partition_point_elements(|e| f(e) != Ordering::Greater);
+
+        self.drain_elements(start, end)
+    }
+
+    /// Remove the elements out of a range from this sorted collection. `f` compares an element with the range, returning `Less` if the element is before the range, `Equal` if it is in the range and `Greater` if it is after the range. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_retain_range_by<F: FnMut(&T) -> Ordering>(&mut self, mut f: F) {
+        let start = self.partition_point_elements(|e| f(e) == Ordering::Less);
+        let end = self.partition_point_elements(|e| f(e) != Ordering::Greater);
+
+        self.retain_elements(start, end)
+    }
+
+    /// Split this sorted collection into two at the first element for which `f` returns `false`, and return the elements from that one. `f` must return `true` for the leading elements and `false` for the rest. Use binary search to find the index.
+    #[inline]
+    fn sorted_split_off_by<F: FnMut(&T) -> bool>(&mut self, f: F) -> Self {
+        let index = self.partition_point_elements(f);
+
+        self.split_off_elements(index)
+    }
+
+    /// Remove the consecutive elements for which `same_bucket` returns `true`, keeping the first or the last one of each run. `same_bucket` is called with the earlier element as the first argument.
+    #[inline]
+    fn sorted_dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, same_bucket: F, keep: DedupKeep) {
+        self.dedup_elements_by(same_bucket, keep)
+    }
+
+    #[doc(hidden)]
+    fn partition_point_elements<P: FnMut(&T) -> bool>(&self, pred: P) -> usize;
+
+    #[doc(hidden)]
+    fn drain_elements(&mut self, start: usize, end: usize) -> Self;
+
+    #[doc(hidden)]
+    fn retain_elements(&mut self, start: usize, end: usize);
+
+    #[doc(hidden)]
+    fn split_off_elements(&mut self, index: usize) -> Self;
+
+    #[doc(hidden)]
+    fn dedup_elements_by<F: FnMut(&T, &T) -> bool>(&mut self, same_bucket: F, keep: DedupKeep);
+}
+
+pub trait SortedBulkByKey<T>: SortedBulkBy<T> {
+    /// Remove the elements whose keys are in a range from this collection sorted in ascending order by a specific key, and return them. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_drain_range_asc_by_key<A: Ord, R: RangeBounds<A>, F: FnMut(&T) -> &A>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) -> Self {
+        self.sorted_drain_range_by(|e| search::range_ordering(&range, f(e)))
+    }
+
+    /// Remove the elements whose keys are in a range from this collection sorted in descending order by a specific key, and return them. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_drain_range_desc_by_key<A: Ord, R: RangeBounds<A>, F: FnMut(&T) -> &A>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) -> Self {
+        self.sorted_drain_range_by(|e| search::range_ordering(&range, f(e)).reverse())
+    }
+
+    /// Remove the elements whose keys are out of a range from this collection sorted in ascending order by a specific key. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_retain_range_asc_by_key<A: Ord, R: RangeBounds<A>, F: FnMut(&T) -> &A>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) {
+        self.sorted_retain_range_by(|e| search::range_ordering(&range, f(e)))
+    }
+
+    /// Remove the elements whose keys are out of a range from this collection sorted in descending order by a specific key. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_retain_range_desc_by_key<A: Ord, R: RangeBounds<A>, F: FnMut(&T) -> &A>(
+        &mut self,
+        range: R,
+        mut f: F,
+    ) {
+        self.sorted_retain_range_by(|e| search::range_ordering(&range, f(e)).reverse())
+    }
+
+    /// Split this collection sorted in ascending order by a specific key into two, and return the elements whose keys are greater than or equal to `key`. Use binary search to find the index.
+    #[inline]
+    fn sorted_split_off_asc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        key: &A,
+        mut f: F,
+    ) -> Self {
+        self.sorted_split_off_by(|e| f(e) < key)
+    }
+
+    /// Split this collection sorted in descending order by a specific key into two, and return the elements whose keys are less than or equal to `key`. Use binary search to find the index.
+    #[inline]
+    fn sorted_split_off_desc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &mut self,
+        key: &A,
+        mut f: F,
+    ) -> Self {
+        self.sorted_split_off_by(|e| f(e) > key)
+    }
+
+    /// Remove the consecutive elements with equal keys, keeping the first or the last one of each run.
+    #[inline]
+    fn sorted_dedup_by_key<A: Eq, F: FnMut(&T) -> &A>(&mut self, mut f: F, keep: DedupKeep) {
+        self.sorted_dedup_by(|a, b| f(a) == f(b), keep)
+    }
+}
+
+pub trait SortedBulk<T: Ord>: SortedBulkByKey<T> {
+    /// Remove the elements in a range from this collection sorted in ascending order, and return them. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_drain_range_asc<R: RangeBounds<T>>(&mut self, range: R) -> Self {
+        self.sorted_drain_range_asc_by_key(range, |element| element)
+    }
+
+    /// Remove the elements in a range from this collection sorted in descending order, and return them. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_drain_range_desc<R: RangeBounds<T>>(&mut self, range: R) -> Self {
+        self.sorted_drain_range_desc_by_key(range, |element| element)
+    }
+
+    /// Remove the elements out of a range from this collection sorted in ascending order. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_retain_range_asc<R: RangeBounds<T>>(&mut self, range: R) {
+        self.sorted_retain_range_asc_by_key(range, |element| element)
+    }
+
+    /// Remove the elements out of a range from this collection sorted in descending order. Use binary search to find the bounds of the range.
+    #[inline]
+    fn sorted_retain_range_desc<R: RangeBounds<T>>(&mut self, range: R) {
+        self.sorted_retain_range_desc_by_key(range, |element| element)
+    }
+
+    /// Split this collection sorted in ascending order into two, and return the elements which are greater than or equal to `element`. Use binary search to find the index.
+    #[inline]
+    fn sorted_split_off_asc(&mut self, element: &T) -> Self {
+        self.sorted_split_off_asc_by_key(element, |element| element)
+    }
+
+    /// Split this collection sorted in descending order into two, and return the elements which are less than or equal to `element`. Use binary search to find the index.
+    #[inline]
+    fn sorted_split_off_desc(&mut self, element: &T) -> Self {
+        self.sorted_split_off_desc_by_key(element, |element| element)
+    }
+
+    /// Remove the consecutive equal elements, keeping the first or the last one of each run.
+    #[inline]
+    fn sorted_dedup(&mut self, keep: DedupKeep) {
+        self.sorted_dedup_by_key(|element| element, keep)
+    }
+}
+
+pub trait SortedRankBy<T>: SortedInsertBasic<T> {
+    /// Return the number of elements ordered before a target in this sorted collection, which is the 0-based rank of the target. `f` compares an element with the target. Use binary search to count the elements.
+    #[inline]
+    fn sorted_rank_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> usize {
+        self.partition_point_by(|e| f(e) == Ordering::Less)
+    }
+
+    /// Return the number of elements in a range in this sorted collection. `f` compares an element with the range, returning `Less` if the element is before the range, `Equal` if it is in the range and `Greater` if it is after the range. Use binary search to count the elements.
+    #[inline]
+    fn sorted_count_in_range_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> usize {
+        let start = self.partition_point_by(|e| f(e) == Ordering::Less);
+        let end = self.partition_point_by(|e| f(e) != Ordering::Greater);
+
+        end - start
+    }
+
+    /// Return the element at the `p`-th percentile (from 0 to 100) of this collection sorted in ascending order, by using the nearest-rank method. Return `None` if this collection is empty.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if `p` is not between 0 and 100.
+    #[inline]
+    fn sorted_percentile_asc(&self, p: f64) -> Option<&T> {
+        let index = percentile_index(self.count_elements(), p)?;
+
+        self.get_element(index)
+    }
+
+    /// Return the element at the `p`-th percentile (from 0 to 100) of this collection sorted in descending order, by using the nearest-rank method. Return `None` if this collection is empty.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if `p` is not between 0 and 100.
+    #[inline]
+    fn sorted_percentile_desc(&self, p: f64) -> Option<&T> {
+        let len = self.count_elements();
+        let index = percentile_index(len, p)?;
+
+        self.get_element(len - 1 - index)
+    }
+
+    /// Return the median of this collection sorted in ascending order. If the number of elements is even, return the lower one of the two middle elements. Return `None` if this collection is empty.
+    #[inline]
+    fn sorted_median_asc(&self) -> Option<&T> {
+        let len = self.count_elements();
+
+        if len == 0 {
+            None
+        } else {
+            self.get_element((len - 1) / 2)
+        }
+    }
+
+    /// Return the median of this collection sorted in descending order. If the number of elements is even, return the lower one of the two middle elements. Return `None` if this collection is empty.
+    #[inline]
+    fn sorted_median_desc(&self) -> Option<&T> {
+        let len = self.count_elements();
+
+        if len == 0 {
+            None
+        } else {
+            self.get_element(len / 2)
+        }
+    }
+
+    #[doc(hidden)]
+    fn count_elements(&self) -> usize;
+
+    #[doc(hidden)]
+    fn partition_point_by<P: FnMut(&T) -> bool>(&self, pred: P) -> usize;
+}
+
+/// Return the index of the `p`-th percentile in ascending order by using the nearest-rank method.
+fn percentile_index(len: usize, p: f64) -> Option<usize> {
+    assert!((0.0..=100.0).contains(&p), "the percentile must be between 0 and 100");
+
+    if len == 0 {
+        return None;
+    }
+
+    let rank = p / 100.0 * len as f64;
+    let mut ceil = rank as usize;
+
+    if (ceil as f64) < rank {
+        ceil += 1;
+    }
+
+    Some(ceil.clamp(1, len) - 1)
+}
+
+pub trait SortedRankByKey<T>: SortedRankBy<T> {
+    /// Return the number of elements whose keys are less than `key` in this collection sorted in ascending order by a specific key, which is the 0-based rank of `key`. Use binary search to count the elements.
+    #[inline]
+    fn sorted_rank_asc_by_key<A: Ord, F: FnMut(&T) -> &A>(&self, key: &A, mut f: F) -> usize {
+        self.sorted_rank_by(|e| f(e).cmp(key))
+    }
+
+    /// Return the number of elements whose keys are greater than `key` in this collection sorted in descending order by a specific key, which is the 0-based rank of `key`. Use binary search to count the elements.
+    #[inline]
+    fn sorted_rank_desc_by_key<A: Ord, F: FnMut(&T) -> &A>(&self, key: &A, mut f: F) -> usize {
+        self.sorted_rank_by(|e| key.cmp(f(e)))
+    }
+
+    /// Return the number of elements whose keys are less than `key` in this collection sorted in ascending order by a specific key. Use binary search to count the elements.
+    #[inline]
+    fn sorted_count_less_than_asc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &self,
+        key: &A,
+        f: F,
+    ) -> usize {
+        self.sorted_rank_asc_by_key(key, f)
+    }
+
+    /// Return the number of elements whose keys are less than `key` in this collection sorted in descending order by a specific key. Use binary search to count the elements.
+    #[inline]
+    fn sorted_count_less_than_desc_by_key<A: Ord, F: FnMut(&T) -> &A>(
+        &self,
+        key: &A,
+        mut f: F,
+    ) -> usize {
+        self.count_elements() - self.partition_point_by(|e| f(e) >= key)
+    }
+
+    /// Return the number of elements whose keys are in a range in this collection sorted in ascending order by a specific key. Use binary search to count the elements.
+    #[inline]
+    fn sorted_count_in_range_asc_by_key<A: Ord, R: RangeBounds<A>, F: FnMut(&T) -> &A>(
+        &self,
+        range: R,
+        mut f: F,
+    ) -> usize {
+        self.sorted_count_in_range_by(|e| search::range_ordering(&range, f(e)))
+    }
+
+    /// Return the number of elements whose keys are in a range in this collection sorted in descending order by a specific key. Use binary search to count the elements.
+    #[inline]
+    fn sorted_count_in_range_desc_by_key<A: Ord, R: RangeBounds<A>, F: FnMut(&T) -> &A>(
+        &self,
+        range: R,
+        mut f: F,
+    ) -> usize {
+        self.sorted_count_in_range_by(|e| search::range_ordering(&range, f(e)).reverse())
+    }
+}
+
+pub trait SortedRank<T: Ord>: SortedRankByKey<T> {
+    /// Return the number of elements less than `element` in this collection sorted in ascending order, which is the 0-based rank of `element`. Use binary search to count the elements.
+    #[inline]
+    fn sorted_rank_asc(&self, element: &T) -> usize {
+        self.sorted_rank_asc_by_key(element, |element| element)
+    }
+
+    /// Return the number of elements greater than `element` in this collection sorted in descending order, which is the 0-based rank of `element`. Use binary search to count the elements.
+    #[inline]
+    fn sorted_rank_desc(&self, element: &T) -> usize {
+        self.sorted_rank_desc_by_key(element, |element| element)
+    }
+
+    /// Return the number of elements less than `element` in this collection sorted in ascending order. Use binary search to count the elements.
+    #[inline]
+    fn sorted_count_less_than_asc(&self, element: &T) -> usize {
+        self.sorted_count_less_than_asc_by_key(element, |element| element)
+    }
+
+    /// Return the number of elements less than `element` in this collection sorted in descending order. Use binary search to count the elements.
+    #[inline]
+    fn sorted_count_less_than_desc(&self, element: &T) -> usize {
+        self.sorted_count_less_than_desc_by_key(element, |element| element)
+    }
+
+    /// Return the number of elements in a range in this collection sorted in ascending order. Use binary search to count the elements.
+    #[inline]
+    fn sorted_count_in_range_asc<R: RangeBounds<T>>(&self, range: R) -> usize {
+        self.sorted_count_in_range_asc_by_key(range, |element| element)
+    }
+
+    /// Return the number of elements in a range in this collection sorted in descending order. Use binary search to count the elements.
+    #[inline]
+    fn sorted_count_in_range_desc<R: RangeBounds<T>>(&self, range: R) -> usize {
+        self.sorted_count_in_range_desc_by_key(range, |element| element)
+    }
 }
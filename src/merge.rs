@@ -0,0 +1,187 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+};
+
+/// Merge the elements of `other`, which must be sorted by `f`, into `v`, which must be sorted by `f` as well.
+///
+/// The elements of `v` are kept before the equal elements of `other`. If `dedup` is `true`, only the first one of consecutive equal elements is kept, so equal elements which are already in `v` are collapsed as well.
+pub(crate) fn merge_vec_by<T, I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+    v: &mut Vec<T>,
+    other: I,
+    dedup: bool,
+    mut f: F,
+) {
+    let other: Vec<T> = other.into_iter().collect();
+
+    let append = match (v.last(), other.first()) {
+        (Some(last), Some(first)) => f(first, last) != Ordering::Less,
+        _ => true,
+    };
+
+    if append {
+        v.extend(other);
+    } else {
+        let left = core::mem::replace(v, Vec::with_capacity(v.len() + other.len()));
+
+        let mut left = left.into_iter().peekable();
+        let mut right = other.into_iter().peekable();
+
+        // take from `other` only when its head is strictly smaller, so the elements of `v` go first on ties
+        loop {
+            let take_right = match (left.peek(), right.peek()) {
+                (Some(a), Some(b)) => f(b, a) == Ordering::Less,
+                (Some(_), None) => {
+                    v.extend(left);
+
+                    break;
+                },
+                (None, _) => {
+                    v.extend(right);
+
+                    break;
+                },
+            };
+
+            if take_right {
+                v.push(right.next().unwrap());
+            } else {
+                v.push(left.next().unwrap());
+            }
+        }
+    }
+
+    if dedup {
+        v.dedup_by(|later, earlier| f(earlier, later) == Ordering::Equal);
+    }
+}
+
+struct Head<I: Iterator> {
+    iter: I,
+    head: Option<I::Item>,
+}
+
+/// A lazy iterator which merges several sorted iterators. Created by `merge_sorted_iters` and `merge_sorted_iters_by`.
+pub struct MergeSortedIters<I: Iterator, F, const N: usize> {
+    heads:   [Head<I>; N],
+    started: bool,
+    f:       F,
+}
+
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> Ordering, const N: usize> Iterator
+    for MergeSortedIters<I, F, N>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.started {
+            for head in self.heads.iter_mut() {
+                head.head = head.iter.next();
+            }
+
+            self.started = true;
+        }
+
+        let mut selected: Option<usize> = None;
+
+        for i in 0..N {
+            if let Some(e) = self.heads[i].head.as_ref() {
+                selected = match selected {
+                    // only a strictly smaller element replaces the selected one, so earlier iterators go first on ties
+                    Some(s)
+                        if (self.f)(e, self.heads[s].head.as_ref().unwrap()) != Ordering::Less =>
+                    {
+                        Some(s)
+                    },
+                    _ => Some(i),
+                };
+            }
+        }
+
+        let head = &mut self.heads[selected?];
+        let next = head.iter.next();
+
+        core::mem::replace(&mut head.head, next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.heads.iter().fold((0, Some(0)), |(low, high), head| {
+            let (l, h) = head.iter.size_hint();
+            let pending = if self.started { head.head.is_some() as usize } else { 0 };
+
+            (
+                low.saturating_add(l).saturating_add(pending),
+                high.and_then(|high| h.and_then(|h| high.checked_add(h)?.checked_add(pending))),
+            )
+        })
+    }
+}
+
+impl<I: Iterator, F: FnMut(&I::Item, &I::Item) -> Ordering, const N: usize> FusedIterator
+    for MergeSortedIters<I, F, N>
+{
+}
+
+impl<I: Iterator, F, const N: usize> Debug for MergeSortedIters<I, F, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeSortedIters").finish()
+    }
+}
+
+/// Lazily merge several iterators, each of which is sorted by a specific comparator, into one sorted iterator without allocation.
+///
+/// Equal elements are yielded in the order of the iterators, so the merge is stable. Each element is found by comparing the heads of all the iterators, so the cost is `O(N)` per element.
+///
+/// ```rust
+/// use sorted_insert::merge_sorted_iters_by;
+///
+/// let merged: Vec<i32> = merge_sorted_iters_by(
+///     [vec![5, 3, 1].into_iter(), vec![4, 2].into_iter()],
+///     |a, b| b.cmp(a),
+/// )
+/// .collect();
+///
+/// assert_eq!(vec![5, 4, 3, 2, 1], merged);
+/// ```
+#[inline]
+pub fn merge_sorted_iters_by<
+    I: IntoIterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+    const N: usize,
+>(
+    iters: [I; N],
+    f: F,
+) -> MergeSortedIters<I::IntoIter, F, N> {
+    MergeSortedIters {
+        heads: iters.map(|iter| Head {
+            iter: iter.into_iter(), head: None
+        }),
+        started: false,
+        f,
+    }
+}
+
+/// Lazily merge several iterators, each of which is sorted in ascending order, into one sorted iterator without allocation.
+///
+/// Equal elements are yielded in the order of the iterators, so the merge is stable.
+///
+/// ```rust
+/// use sorted_insert::merge_sorted_iters;
+///
+/// let merged: Vec<i32> =
+///     merge_sorted_iters([vec![1, 4], vec![2, 3], vec![0, 5]]).collect();
+///
+/// assert_eq!(vec![0, 1, 2, 3, 4, 5], merged);
+/// ```
+#[allow(clippy::type_complexity)]
+#[inline]
+pub fn merge_sorted_iters<I: IntoIterator, const N: usize>(
+    iters: [I; N],
+) -> MergeSortedIters<I::IntoIter, fn(&I::Item, &I::Item) -> Ordering, N>
+where
+    I::Item: Ord, {
+    merge_sorted_iters_by(iters, Ord::cmp as fn(&I::Item, &I::Item) -> Ordering)
+}
@@ -0,0 +1,50 @@
+use core::cmp::Ordering;
+
+/// How to place elements which are not comparable even with themselves (such as `NaN`) when inserting into a collection sorted by `PartialOrd`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NanPolicy {
+    /// Refuse to insert such elements and give them back. The collection is assumed to contain no such elements.
+    Reject,
+    /// Put such elements at the front of the collection.
+    First,
+    /// Put such elements at the back of the collection.
+    Last,
+}
+
+impl NanPolicy {
+    /// Compare `a` with `b` in the order of a collection sorted in ascending (or descending if `descending` is `true`) order by `PartialOrd`, placing incomparable elements according to this policy.
+    pub(crate) fn compare<A: PartialOrd + ?Sized>(
+        self,
+        a: &A,
+        b: &A,
+        descending: bool,
+    ) -> Ordering {
+        let a_is_nan = a.partial_cmp(a).is_none();
+        let b_is_nan = b.partial_cmp(b).is_none();
+
+        match (a_is_nan, b_is_nan) {
+            (false, false) => {
+                let ordering = if descending { b.partial_cmp(a) } else { a.partial_cmp(b) };
+
+                ordering.unwrap_or(Ordering::Equal)
+            },
+            (true, true) => Ordering::Equal,
+            (true, false) => match self {
+                NanPolicy::First => Ordering::Less,
+                NanPolicy::Last | NanPolicy::Reject => Ordering::Greater,
+            },
+            (false, true) => match self {
+                NanPolicy::First => Ordering::Greater,
+                NanPolicy::Last | NanPolicy::Reject => Ordering::Less,
+            },
+        }
+    }
+
+    /// Return `true` if the element should be rejected by this policy.
+    #[inline]
+    pub(crate) fn rejects<A: PartialOrd + ?Sized>(self, element: &A) -> bool {
+        let is_nan = element.partial_cmp(element).is_none();
+
+        self == NanPolicy::Reject && is_nan
+    }
+}
@@ -0,0 +1,55 @@
+use core::cmp::Ordering;
+
+/// The direction in which a collection is sorted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Order {
+    /// From the smallest to the largest.
+    Ascending,
+    /// From the largest to the smallest.
+    Descending,
+}
+
+impl Order {
+    /// Return the opposite direction.
+    #[inline]
+    pub const fn reverse(self) -> Order {
+        match self {
+            Order::Ascending => Order::Descending,
+            Order::Descending => Order::Ascending,
+        }
+    }
+
+    /// Return `true` if this is `Order::Ascending`.
+    #[inline]
+    pub const fn is_ascending(self) -> bool {
+        matches!(self, Order::Ascending)
+    }
+
+    /// Return `true` if this is `Order::Descending`.
+    #[inline]
+    pub const fn is_descending(self) -> bool {
+        matches!(self, Order::Descending)
+    }
+
+    /// Convert an `Ordering` of ascending order to the `Ordering` in this direction.
+    #[inline]
+    pub const fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Order::Ascending => ordering,
+            Order::Descending => ordering.reverse(),
+        }
+    }
+
+    /// Compare two values in this direction.
+    #[inline]
+    pub fn compare<A: Ord + ?Sized>(self, a: &A, b: &A) -> Ordering {
+        self.apply(a.cmp(b))
+    }
+}
+
+impl Default for Order {
+    #[inline]
+    fn default() -> Self {
+        Order::Ascending
+    }
+}
@@ -0,0 +1,175 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use ::rayon::prelude::*;
+
+/// The minimum number of elements of a merged segment, so that small merges are not split into too many tasks.
+const MIN_SEGMENT_LEN: usize = 4096;
+
+pub trait ParSortedExtendBy<T: Send> {
+    /// Insert elements to this sorted collection by a specific comparator in bulk. The elements are sorted in parallel with a stable sort and then merged into this collection in parallel. The result is the same as inserting the elements one by one with `sorted_insert_by`, so equal elements are placed after the existing ones and keep their order in `elements`.
+    fn par_sorted_extend_by<I: IntoParallelIterator<Item = T>, F: Fn(&T, &T) -> Ordering + Sync>(
+        &mut self,
+        elements: I,
+        f: F,
+    );
+}
+
+pub trait ParSortedExtendByKey<T: Send>: ParSortedExtendBy<T> {
+    /// Insert elements to this sorted collection in ascending order by a specific key in bulk. The elements are sorted and merged in parallel, and the result is the same as inserting them one by one with `sorted_insert_asc_by_key`.
+    #[inline]
+    fn par_sorted_extend_asc_by_key<
+        I: IntoParallelIterator<Item = T>,
+        A: Ord,
+        F: Fn(&T) -> &A + Sync,
+    >(
+        &mut self,
+        elements: I,
+        f: F,
+    ) {
+        self.par_sorted_extend_by(elements, |a, b| f(a).cmp(f(b)))
+    }
+
+    /// Insert elements to this sorted collection in descending order by a specific key in bulk. The elements are sorted and merged in parallel, and the result is the same as inserting them one by one with `sorted_insert_desc_by_key`.
+    #[inline]
+    fn par_sorted_extend_desc_by_key<
+        I: IntoParallelIterator<Item = T>,
+        A: Ord,
+        F: Fn(&T) -> &A + Sync,
+    >(
+        &mut self,
+        elements: I,
+        f: F,
+    ) {
+        self.par_sorted_extend_by(elements, |a, b| f(b).cmp(f(a)))
+    }
+}
+
+pub trait ParSortedExtend<T: Ord + Send>: ParSortedExtendByKey<T> {
+    /// Insert elements to this sorted collection in ascending order in bulk. The elements are sorted and merged in parallel, and the result is the same as inserting them one by one with `sorted_insert_asc`.
+    #[inline]
+    fn par_sorted_extend_asc<I: IntoParallelIterator<Item = T>>(&mut self, elements: I) {
+        self.par_sorted_extend_asc_by_key(elements, |element| element)
+    }
+
+    /// Insert elements to this sorted collection in descending order in bulk. The elements are sorted and merged in parallel, and the result is the same as inserting them one by one with `sorted_insert_desc`.
+    #[inline]
+    fn par_sorted_extend_desc<I: IntoParallelIterator<Item = T>>(&mut self, elements: I) {
+        self.par_sorted_extend_desc_by_key(elements, |element| element)
+    }
+}
+
+impl<T: Send> ParSortedExtendBy<T> for Vec<T> {
+    fn par_sorted_extend_by<I: IntoParallelIterator<Item = T>, F: Fn(&T, &T) -> Ordering + Sync>(
+        &mut self,
+        elements: I,
+        f: F,
+    ) {
+        let mut right: Vec<T> = elements.into_par_iter().collect();
+
+        if right.is_empty() {
+            return;
+        }
+
+        right.par_sort_by(&f);
+
+        let left = core::mem::take(self);
+
+        *self = par_merge_by(left, right, &f);
+    }
+}
+
+impl<T: Send> ParSortedExtendByKey<T> for Vec<T> {}
+
+impl<T: Ord + Send> ParSortedExtend<T> for Vec<T> {}
+
+/// Merge two sorted `Vec`s in parallel. The elements of `left` are placed before the equal elements of `right`.
+///
+/// Both sides are cut into segments at positions which are prefixes of the merged order, so each pair of segments can be merged independently.
+fn par_merge_by<T: Send, F: Fn(&T, &T) -> Ordering + Sync>(
+    mut left: Vec<T>,
+    mut right: Vec<T>,
+    f: &F,
+) -> Vec<T> {
+    let len = left.len() + right.len();
+    let segments = (len / MIN_SEGMENT_LEN).clamp(1, ::rayon::current_num_threads() * 4);
+
+    // each cut is `(i, j)`, meaning that the first `i` elements of `left` and the first `j` elements of `right` are merged before the others
+    let mut cuts = Vec::with_capacity(segments * 2);
+
+    for k in 1..segments {
+        let i = left.len() * k / segments;
+
+        if i < left.len() {
+            // the elements of `right` which are less than `left[i]` go before it
+            let j = right.partition_point(|r| f(r, &left[i]) == Ordering::Less);
+
+            cuts.push((i, j));
+        }
+
+        let j = right.len() * k / segments;
+
+        if j < right.len() {
+            // the elements of `left` which are not greater than `right[j]` go before it
+            let i = left.partition_point(|l| f(&right[j], l) != Ordering::Less);
+
+            cuts.push((i, j));
+        }
+    }
+
+    // the cuts are prefixes of the merged order, so both of their coordinates grow together
+    cuts.sort_unstable_by_key(|(i, j)| i + j);
+    cuts.dedup();
+
+    let mut pairs = Vec::with_capacity(cuts.len() + 1);
+
+    for (i, j) in cuts.into_iter().rev() {
+        pairs.push((left.split_off(i), right.split_off(j)));
+    }
+
+    pairs.push((left, right));
+    pairs.reverse();
+
+    let merged: Vec<Vec<T>> =
+        pairs.into_par_iter().map(|(left, right)| merge_by(left, right, f)).collect();
+
+    let mut result = Vec::with_capacity(len);
+
+    for mut segment in merged {
+        result.append(&mut segment);
+    }
+
+    result
+}
+
+/// Merge two sorted `Vec`s. The elements of `left` are placed before the equal elements of `right`.
+fn merge_by<T, F: Fn(&T, &T) -> Ordering>(left: Vec<T>, right: Vec<T>, f: &F) -> Vec<T> {
+    let mut result = Vec::with_capacity(left.len() + right.len());
+
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+
+    loop {
+        let take_right = match (left.peek(), right.peek()) {
+            (Some(l), Some(r)) => f(r, l) == Ordering::Less,
+            (Some(_), None) => {
+                result.extend(left);
+
+                break;
+            },
+            (None, _) => {
+                result.extend(right);
+
+                break;
+            },
+        };
+
+        if take_right {
+            result.push(right.next().unwrap());
+        } else {
+            result.push(left.next().unwrap());
+        }
+    }
+
+    result
+}
@@ -0,0 +1,215 @@
+//! Strategies for generating sorted collections with `proptest`. This module is available when the `proptest` feature is enabled.
+//!
+//! ```rust
+//! use proptest::prelude::*;
+//! use sorted_insert::{proptest::sorted_vec, Order, SortedInsertBinary};
+//!
+//! proptest! {
+//!     fn insert_keeps_order(mut v in sorted_vec(0..100i32, 0..50, Order::Descending), e in 0..100i32) {
+//!         v.sorted_insert_desc_binary(e);
+//!
+//!         prop_assert!(sorted_insert::is_sorted_desc(&v));
+//!     }
+//! }
+//!
+//! insert_keeps_order();
+//! ```
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use ::proptest::{
+    arbitrary::{any, any_with, Arbitrary},
+    collection::{btree_map, btree_set, vec, SizeRange},
+    sample::Index,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+
+use crate::{Order, SortedBag, SortedChunkedVec, SortedVecMap};
+
+#[inline]
+fn sort<T: Ord>(v: &mut [T], order: Order) {
+    v.sort_by(|a, b| order.compare(a, b));
+}
+
+/// Generate `Vec`s of elements sorted in the given order. Equal elements may appear, as often as `element` generates them.
+#[inline]
+pub fn sorted_vec<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+    order: Order,
+) -> impl Strategy<Value = Vec<S::Value>>
+where
+    S::Value: Ord, {
+    vec(element, size).prop_map(move |mut v| {
+        sort(&mut v, order);
+
+        v
+    })
+}
+
+/// Generate `Vec`s of distinct elements sorted in the given order.
+///
+/// `element` must be able to generate enough distinct values for `size`, or the generation will be rejected.
+#[inline]
+pub fn sorted_unique_vec<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+    order: Order,
+) -> impl Strategy<Value = Vec<S::Value>>
+where
+    S::Value: Ord, {
+    btree_set(element, size).prop_map(move |set| {
+        let mut v: Vec<S::Value> = set.into_iter().collect();
+
+        if order.is_descending() {
+            v.reverse();
+        }
+
+        v
+    })
+}
+
+/// Generate `Vec`s sorted in the given order with controllable duplicates. There are `distinct` distinct elements, each of which is repeated a number of times generated by `run_len`.
+#[inline]
+pub fn sorted_vec_with_runs<S: Strategy, R: Strategy<Value = usize> + Clone>(
+    element: S,
+    distinct: impl Into<SizeRange>,
+    run_len: R,
+    order: Order,
+) -> impl Strategy<Value = Vec<S::Value>>
+where
+    S::Value: Ord + Clone, {
+    btree_set(element, distinct)
+        .prop_flat_map(move |set| {
+            let len = set.len();
+
+            (Just(set), vec(run_len.clone(), len))
+        })
+        .prop_map(move |(set, run_lens)| {
+            let mut v = Vec::new();
+
+            for (element, run_len) in set.into_iter().zip(run_lens) {
+                v.extend(core::iter::repeat_n(element, run_len));
+            }
+
+            if order.is_descending() {
+                v.reverse();
+            }
+
+            v
+        })
+}
+
+/// Generate `VecDeque`s of elements sorted in the given order.
+#[cfg(feature = "std")]
+#[inline]
+pub fn sorted_vec_deque<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+    order: Order,
+) -> impl Strategy<Value = VecDeque<S::Value>>
+where
+    S::Value: Ord, {
+    sorted_vec(element, size, order).prop_map(VecDeque::from)
+}
+
+/// Generate `SortedVecMap`s.
+#[inline]
+pub fn sorted_vec_map<K: Strategy, V: Strategy>(
+    key: K,
+    value: V,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = SortedVecMap<K::Value, V::Value>>
+where
+    K::Value: Ord, {
+    btree_map(key, value, size).prop_map(|map| map.into_iter().collect())
+}
+
+/// Generate `SortedBag`s with `size` elements, counting duplicates.
+#[inline]
+pub fn sorted_bag<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = SortedBag<S::Value>>
+where
+    S::Value: Ord, {
+    vec(element, size).prop_map(|v| v.into_iter().collect())
+}
+
+/// Generate `SortedChunkedVec`s with chunk sizes from 2 to 8, so that even a few elements are spread over several chunks which are split and merged when they are modified.
+#[inline]
+pub fn sorted_chunked_vec<S: Strategy>(
+    element: S,
+    size: impl Into<SizeRange>,
+) -> impl Strategy<Value = SortedChunkedVec<S::Value>>
+where
+    S::Value: Ord, {
+    (vec(element, size), 2..=8usize)
+        .prop_map(|(v, chunk_size)| SortedChunkedVec::from_vec_with_chunk_size(v, chunk_size))
+}
+
+/// Generate sequences of elements to be inserted one by one, in no particular order. The elements are drawn from a pool of `distinct` generated values, so a small pool produces many duplicates.
+#[inline]
+pub fn insertion_sequence<S: Strategy>(
+    element: S,
+    distinct: impl Into<SizeRange>,
+    len: impl Into<SizeRange>,
+) -> impl Strategy<Value = Vec<S::Value>>
+where
+    S::Value: Clone, {
+    (vec(element, distinct), vec(any::<Index>(), len)).prop_map(|(pool, indices)| {
+        if pool.is_empty() {
+            Vec::new()
+        } else {
+            indices.iter().map(|index| pool[index.index(pool.len())].clone()).collect()
+        }
+    })
+}
+
+impl<K: Arbitrary + Ord, V: Arbitrary> Arbitrary for SortedVecMap<K, V>
+where
+    K::Strategy: 'static,
+    V::Strategy: 'static,
+{
+    type Parameters = (SizeRange, K::Parameters, V::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    #[inline]
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        let (size, key, value) = args;
+
+        sorted_vec_map(any_with::<K>(key), any_with::<V>(value), size).boxed()
+    }
+}
+
+impl<T: Arbitrary + Ord> Arbitrary for SortedBag<T>
+where
+    T::Strategy: 'static,
+{
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    #[inline]
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        let (size, element) = args;
+
+        sorted_bag(any_with::<T>(element), size).boxed()
+    }
+}
+
+impl<T: Arbitrary + Ord> Arbitrary for SortedChunkedVec<T>
+where
+    T::Strategy: 'static,
+{
+    type Parameters = (SizeRange, T::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    #[inline]
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        let (size, element) = args;
+
+        sorted_chunked_vec(any_with::<T>(element), size).boxed()
+    }
+}
@@ -0,0 +1,173 @@
+//! `Arbitrary` implementations for generating sorted collections with `quickcheck`. This module is available when the `quickcheck` feature is enabled.
+//!
+//! The newtypes in this module wrap sorted `Vec`s and `VecDeque`s, and shrink to smaller collections which are still sorted.
+//!
+//! ```rust
+//! use quickcheck::{quickcheck, TestResult};
+//! use sorted_insert::{quickcheck::SortedDescVec, SortedInsertBinary};
+//!
+//! fn insert_keeps_order(v: SortedDescVec<i32>, e: i32) -> TestResult {
+//!     let mut v = v.0;
+//!
+//!     v.sorted_insert_desc_binary(e);
+//!
+//!     TestResult::from_bool(sorted_insert::is_sorted_desc(&v))
+//! }
+//!
+//! quickcheck(insert_keeps_order as fn(SortedDescVec<i32>, i32) -> TestResult);
+//! ```
+
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use ::quickcheck::{Arbitrary, Gen};
+
+use crate::{Order, SortedBag, SortedChunkedVec, SortedVecMap};
+
+#[inline]
+fn sorted<T: Ord>(mut v: Vec<T>, order: Order) -> Vec<T> {
+    v.sort_by(|a, b| order.compare(a, b));
+
+    v
+}
+
+macro_rules! impl_sorted_newtype {
+    ($(#[$attr:meta])* $name:ident, $collection:ident, $order:expr, |$v:ident| $after:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name<T>(pub $collection<T>);
+
+        impl<T: Arbitrary + Ord> Arbitrary for $name<T> {
+            #[inline]
+            fn arbitrary(g: &mut Gen) -> Self {
+                let $v = sorted(Vec::<T>::arbitrary(g), $order);
+
+                $name($after.into())
+            }
+
+            #[inline]
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                let v: Vec<T> = self.0.iter().cloned().collect();
+
+                Box::new(v.shrink().map(|$v| $name(sorted($after, $order).into())))
+            }
+        }
+    };
+}
+
+impl_sorted_newtype!(
+    /// A `Vec` sorted in ascending order.
+    SortedAscVec,
+    Vec,
+    Order::Ascending,
+    |v| v
+);
+
+impl_sorted_newtype!(
+    /// A `Vec` sorted in descending order.
+    SortedDescVec,
+    Vec,
+    Order::Descending,
+    |v| v
+);
+
+impl_sorted_newtype!(
+    /// A `Vec` of distinct elements sorted in ascending order.
+    SortedUniqueAscVec,
+    Vec,
+    Order::Ascending,
+    |v| {
+        let mut v = v;
+
+        v.dedup();
+
+        v
+    }
+);
+
+#[cfg(feature = "std")]
+impl_sorted_newtype!(
+    /// A `VecDeque` sorted in ascending order.
+    SortedAscVecDeque,
+    VecDeque,
+    Order::Ascending,
+    |v| v
+);
+
+#[cfg(feature = "std")]
+impl_sorted_newtype!(
+    /// A `VecDeque` sorted in descending order.
+    SortedDescVecDeque,
+    VecDeque,
+    Order::Descending,
+    |v| v
+);
+
+/// A sequence of elements to be inserted one by one, in no particular order. The elements are drawn from a small pool of generated values, so the sequence has many duplicates.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InsertionSequence<T>(pub Vec<T>);
+
+impl<T: Arbitrary> Arbitrary for InsertionSequence<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let pool_size = g.size() / 4 + 1;
+        let pool: Vec<T> = (0..pool_size).map(|_| T::arbitrary(g)).collect();
+
+        let len = usize::arbitrary(g) % (g.size() + 1);
+
+        InsertionSequence((0..len).map(|_| g.choose(&pool).unwrap().clone()).collect())
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().map(InsertionSequence))
+    }
+}
+
+impl<K: Arbitrary + Ord, V: Arbitrary> Arbitrary for SortedVecMap<K, V> {
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> Self {
+        Vec::<(K, V)>::arbitrary(g).into_iter().collect()
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.clone().into_vec().shrink().map(|v| v.into_iter().collect()))
+    }
+}
+
+impl<T: Arbitrary + Ord> Arbitrary for SortedBag<T> {
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> Self {
+        Vec::<T>::arbitrary(g).into_iter().collect()
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let v: Vec<T> = self.iter().cloned().collect();
+
+        Box::new(v.shrink().map(|v| v.into_iter().collect()))
+    }
+}
+
+impl<T: Arbitrary + Ord> Arbitrary for SortedChunkedVec<T> {
+    #[inline]
+    fn arbitrary(g: &mut Gen) -> Self {
+        // small chunks, so that even a few elements are spread over several chunks
+        let chunk_size = 2 + usize::arbitrary(g) % 7;
+
+        SortedChunkedVec::from_vec_with_chunk_size(Vec::<T>::arbitrary(g), chunk_size)
+    }
+
+    #[inline]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let chunk_size = self.chunk_size();
+
+        Box::new(
+            self.clone()
+                .into_vec()
+                .shrink()
+                .map(move |v| SortedChunkedVec::from_vec_with_chunk_size(v, chunk_size)),
+        )
+    }
+}
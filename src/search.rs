@@ -0,0 +1,148 @@
+use core::{
+    cmp::Ordering,
+    ops::{Bound, RangeBounds},
+};
+
+use crate::InterpolateKey;
+
+/// Find the index after the last element which is not ordered after the target, by using exponential search starting from the `hint` index.
+///
+/// `f` compares an element of the slice with the target. The cost is `O(log d)` where `d` is the distance between `hint` and the returned index.
+pub(crate) fn gallop_by<T, F: FnMut(&T) -> Ordering>(slice: &[T], hint: usize, mut f: F) -> usize {
+    let len = slice.len();
+    let hint = hint.min(len);
+
+    let (low, high) = if hint > 0 && f(&slice[hint - 1]) == Ordering::Greater {
+        // the index is before `hint`, gallop leftward
+        let mut high = hint - 1;
+        let mut step = 1;
+
+        loop {
+            if high < step {
+                break (0, high);
+            }
+
+            let probe = high - step;
+
+            if f(&slice[probe]) == Ordering::Greater {
+                high = probe;
+                step *= 2;
+            } else {
+                break (probe + 1, high);
+            }
+        }
+    } else {
+        // the index is at or after `hint`, gallop rightward
+        let mut low = hint;
+        let mut step = 1;
+
+        loop {
+            let probe = low + step - 1;
+
+            if probe >= len {
+                break (low, len);
+            }
+
+            if f(&slice[probe]) == Ordering::Greater {
+                break (low, probe);
+            } else {
+                low = probe + 1;
+                step *= 2;
+            }
+        }
+    };
+
+    low + slice[low..high].partition_point(|e| f(e) != Ordering::Greater)
+}
+
+/// Find the index after the last element whose key is not ordered after `key`, by using interpolation search.
+///
+/// Each probe which fails to halve the searched range uses up a budget of `log2(n)` probes. After the budget runs out, which happens when the keys are not uniformly distributed, the rest of the range is searched by binary search, so the cost is `O(log n)` in the worst case.
+pub(crate) fn interpolation_by_key<T, A: InterpolateKey + ?Sized, F: FnMut(&T) -> &A>(
+    slice: &[T],
+    key: &A,
+    mut f: F,
+    descending: bool,
+) -> usize {
+    let order = |e: &A| {
+        if descending {
+            key.compare(e)
+        } else {
+            e.compare(key)
+        }
+    };
+
+    let mut low = 0;
+    let mut high = slice.len();
+    let mut budget = (usize::BITS - high.leading_zeros()) as usize;
+
+    while low < high {
+        if budget == 0 {
+            return low + slice[low..high].partition_point(|e| order(f(e)) != Ordering::Greater);
+        }
+
+        let size = high - low;
+
+        let fraction = {
+            let first = f(&slice[low]);
+            let last = f(&slice[high - 1]);
+
+            if descending {
+                1.0 - key.fraction(last, first)
+            } else {
+                key.fraction(first, last)
+            }
+        };
+
+        let fraction = if fraction >= 0.0 {
+            fraction.min(1.0)
+        } else if fraction < 0.0 {
+            0.0
+        } else {
+            // NaN
+            0.5
+        };
+
+        let probe = low + (fraction * (size - 1) as f64) as usize;
+
+        if order(f(&slice[probe])) == Ordering::Greater {
+            high = probe;
+        } else {
+            low = probe + 1;
+        }
+
+        if (high - low) * 2 > size {
+            budget -= 1;
+        }
+    }
+
+    low
+}
+
+/// Compare `key` with `range`. Return `Less` if `key` is before the start bound, `Greater` if `key` is after the end bound, and `Equal` if `key` is in the range.
+pub(crate) fn range_ordering<A: Ord + ?Sized, R: RangeBounds<A> + ?Sized>(
+    range: &R,
+    key: &A,
+) -> Ordering {
+    let after_start = match range.start_bound() {
+        Bound::Included(start) => key >= start,
+        Bound::Excluded(start) => key > start,
+        Bound::Unbounded => true,
+    };
+
+    if !after_start {
+        return Ordering::Less;
+    }
+
+    let before_end = match range.end_bound() {
+        Bound::Included(end) => key <= end,
+        Bound::Excluded(end) => key < end,
+        Bound::Unbounded => true,
+    };
+
+    if before_end {
+        Ordering::Equal
+    } else {
+        Ordering::Greater
+    }
+}
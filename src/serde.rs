@@ -0,0 +1,144 @@
+//! Serialization and deserialization with `serde`. This module is available when the `serde` feature is enabled.
+//!
+//! `SortedVecMap`, `SortedBag` and `SortedChunkedVec` implement `Serialize` and `Deserialize`. Their deserialization rejects input which is out of order (or has duplicate keys) with an error that tells where the order breaks. To sort such input instead, put `#[serde(with = "sorted_insert::serde::resort")]` on the field.
+//!
+//! Plain `Vec` fields can be checked with the `asc` and `desc` modules, or sorted with the `asc_resort` and `desc_resort` modules.
+//!
+//! ```rust
+//! use serde::{Deserialize, Serialize};
+//! use sorted_insert::SortedVecMap;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Index {
+//!     #[serde(with = "sorted_insert::serde::asc")]
+//!     ids:    Vec<u32>,
+//!     #[serde(with = "sorted_insert::serde::desc_resort")]
+//!     scores: Vec<u32>,
+//!     #[serde(with = "sorted_insert::serde::resort")]
+//!     names:  SortedVecMap<String, u32>,
+//! }
+//!
+//! let index: Index = serde_json::from_str(
+//!     r#"{ "ids": [1, 2, 5], "scores": [3, 9, 4], "names": { "b": 2, "a": 1 } }"#,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!([9, 4, 3], index.scores.as_slice());
+//! assert_eq!(Some(&1), index.names.get("a"));
+//!
+//! assert!(serde_json::from_str::<Index>(r#"{ "ids": [2, 1], "scores": [], "names": {} }"#).is_err());
+//! ```
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Types which can be deserialized from out-of-order input by sorting it. Used by the `resort` module.
+pub trait DeserializeResorted<'de>: Sized {
+    /// Deserialize a value, sorting the input if it is out of order.
+    fn deserialize_resorted<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+/// Deserialize a sorted wrapper type such as `SortedVecMap` by sorting out-of-order input, for `#[serde(with = "sorted_insert::serde::resort")]`.
+pub mod resort {
+    use super::*;
+
+    /// Serialize the value as usual.
+    #[inline]
+    pub fn serialize<T: Serialize + ?Sized, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    /// Deserialize the value, sorting the input if it is out of order.
+    #[inline]
+    pub fn deserialize<'de, T: DeserializeResorted<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        T::deserialize_resorted(deserializer)
+    }
+}
+
+/// Check that a `Vec` field is sorted in ascending order when it is deserialized, for `#[serde(with = "sorted_insert::serde::asc")]`.
+pub mod asc {
+    use alloc::vec::Vec;
+
+    use ::serde::de::Error;
+
+    pub use super::resort::serialize;
+    use super::*;
+
+    /// Deserialize the elements from a sequence. Return an error if they are not in ascending order.
+    #[inline]
+    pub fn deserialize<'de, T: Ord + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let v = Vec::<T>::deserialize(deserializer)?;
+
+        crate::check_sorted_asc(&v).map_err(D::Error::custom)?;
+
+        Ok(v)
+    }
+}
+
+/// Check that a `Vec` field is sorted in descending order when it is deserialized, for `#[serde(with = "sorted_insert::serde::desc")]`.
+pub mod desc {
+    use alloc::vec::Vec;
+
+    use ::serde::de::Error;
+
+    pub use super::resort::serialize;
+    use super::*;
+
+    /// Deserialize the elements from a sequence. Return an error if they are not in descending order.
+    #[inline]
+    pub fn deserialize<'de, T: Ord + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let v = Vec::<T>::deserialize(deserializer)?;
+
+        crate::check_sorted_desc(&v).map_err(D::Error::custom)?;
+
+        Ok(v)
+    }
+}
+
+/// Sort a `Vec` field in ascending order when it is deserialized, for `#[serde(with = "sorted_insert::serde::asc_resort")]`.
+pub mod asc_resort {
+    use alloc::vec::Vec;
+
+    pub use super::resort::serialize;
+    use super::*;
+
+    /// Deserialize the elements from a sequence and sort them in ascending order. Equal elements keep their order in the input.
+    #[inline]
+    pub fn deserialize<'de, T: Ord + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let mut v = Vec::<T>::deserialize(deserializer)?;
+
+        v.sort();
+
+        Ok(v)
+    }
+}
+
+/// Sort a `Vec` field in descending order when it is deserialized, for `#[serde(with = "sorted_insert::serde::desc_resort")]`.
+pub mod desc_resort {
+    use alloc::vec::Vec;
+
+    pub use super::resort::serialize;
+    use super::*;
+
+    /// Deserialize the elements from a sequence and sort them in descending order. Equal elements keep their order in the input.
+    #[inline]
+    pub fn deserialize<'de, T: Ord + Deserialize<'de>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let mut v = Vec::<T>::deserialize(deserializer)?;
+
+        v.sort_by(|a, b| b.cmp(a));
+
+        Ok(v)
+    }
+}
@@ -0,0 +1,256 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+};
+
+use crate::Comparator;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum SetOperation {
+    Union,
+    Intersection,
+    Difference,
+    SymmetricDifference,
+}
+
+impl SetOperation {
+    /// Return whether to keep the elements which are only in the left collection, only in the right collection, and in both collections.
+    #[inline]
+    fn keeps(self) -> (bool, bool, bool) {
+        match self {
+            SetOperation::Union => (true, true, true),
+            SetOperation::Intersection => (false, false, true),
+            SetOperation::Difference => (true, false, false),
+            SetOperation::SymmetricDifference => (true, true, false),
+        }
+    }
+}
+
+/// Replace `v` with the result of a set operation on `v` and `other`, both of which must be sorted by `f`. Equal elements are paired one by one, and the paired elements are taken from `v`.
+pub(crate) fn apply_vec_by<T, I: IntoIterator<Item = T>, F: FnMut(&T, &T) -> Ordering>(
+    v: &mut Vec<T>,
+    other: I,
+    operation: SetOperation,
+    mut f: F,
+) {
+    let (keep_left, keep_right, keep_both) = operation.keeps();
+
+    let mut left = core::mem::take(v).into_iter().peekable();
+    let mut right = other.into_iter().peekable();
+
+    let mut result = Vec::with_capacity(left.len());
+
+    loop {
+        let ordering = match (left.peek(), right.peek()) {
+            (Some(a), Some(b)) => f(a, b),
+            (Some(_), None) => {
+                if keep_left {
+                    result.extend(left);
+                }
+
+                break;
+            },
+            (None, Some(_)) => {
+                if keep_right {
+                    result.extend(right);
+                }
+
+                break;
+            },
+            (None, None) => break,
+        };
+
+        match ordering {
+            Ordering::Less => {
+                let a = left.next().unwrap();
+
+                if keep_left {
+                    result.push(a);
+                }
+            },
+            Ordering::Greater => {
+                let b = right.next().unwrap();
+
+                if keep_right {
+                    result.push(b);
+                }
+            },
+            Ordering::Equal => {
+                let a = left.next().unwrap();
+
+                right.next();
+
+                if keep_both {
+                    result.push(a);
+                }
+            },
+        }
+    }
+
+    *v = result;
+}
+
+/// A cursor over a sorted collection which is stored in up to two contiguous slices, such as a `VecDeque`.
+struct SortedSlices<'a, T> {
+    front: &'a [T],
+    back:  &'a [T],
+}
+
+impl<'a, T> SortedSlices<'a, T> {
+    #[inline]
+    fn new((front, back): (&'a [T], &'a [T])) -> Self {
+        if front.is_empty() {
+            SortedSlices {
+                front: back, back: &[]
+            }
+        } else {
+            SortedSlices {
+                front,
+                back,
+            }
+        }
+    }
+
+    #[inline]
+    fn split_first(&self) -> Option<(&'a T, Self)> {
+        self.front.split_first().map(|(first, rest)| (first, SortedSlices::new((rest, self.back))))
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+}
+
+impl<'a, T> Clone for SortedSlices<'a, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for SortedSlices<'a, T> {}
+
+impl<'a, T: Debug> Debug for SortedSlices<'a, T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.front.iter().chain(self.back)).finish()
+    }
+}
+
+/// A lazy iterator over the result of a set operation on two sorted collections, such as `sorted_union_asc`. The collections are treated as multisets, so equal elements are paired one by one.
+pub struct SetOperationIter<'a, T, C> {
+    left:       SortedSlices<'a, T>,
+    right:      SortedSlices<'a, T>,
+    operation:  SetOperation,
+    comparator: C,
+}
+
+impl<'a, T, C> SetOperationIter<'a, T, C> {
+    /// `left` and `right` are the contents of the collections, each given as up to two slices which are logically concatenated.
+    #[inline]
+    pub(crate) fn new(
+        left: (&'a [T], &'a [T]),
+        right: (&'a [T], &'a [T]),
+        operation: SetOperation,
+        comparator: C,
+    ) -> Self {
+        SetOperationIter {
+            left: SortedSlices::new(left),
+            right: SortedSlices::new(right),
+            operation,
+            comparator,
+        }
+    }
+}
+
+impl<'a, T, C: Comparator<T>> Iterator for SetOperationIter<'a, T, C> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (keep_left, keep_right, keep_both) = self.operation.keeps();
+
+        loop {
+            match (self.left.split_first(), self.right.split_first()) {
+                (Some((a, left)), Some((b, right))) => match self.comparator.compare(a, b) {
+                    Ordering::Less => {
+                        self.left = left;
+
+                        if keep_left {
+                            return Some(a);
+                        }
+                    },
+                    Ordering::Greater => {
+                        self.right = right;
+
+                        if keep_right {
+                            return Some(b);
+                        }
+                    },
+                    Ordering::Equal => {
+                        self.left = left;
+                        self.right = right;
+
+                        if keep_both {
+                            return Some(a);
+                        }
+                    },
+                },
+                (Some((a, left)), None) if keep_left => {
+                    self.left = left;
+
+                    return Some(a);
+                },
+                (None, Some((b, right))) if keep_right => {
+                    self.right = right;
+
+                    return Some(b);
+                },
+                _ => {
+                    self.left = SortedSlices::new((&[], &[]));
+                    self.right = SortedSlices::new((&[], &[]));
+
+                    return None;
+                },
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (left, right) = (self.left.len(), self.right.len());
+
+        match self.operation {
+            SetOperation::Union => (left.max(right), Some(left + right)),
+            SetOperation::Intersection => (0, Some(left.min(right))),
+            SetOperation::Difference => (left.saturating_sub(right), Some(left)),
+            SetOperation::SymmetricDifference => (0, Some(left + right)),
+        }
+    }
+}
+
+impl<'a, T, C: Comparator<T>> FusedIterator for SetOperationIter<'a, T, C> {}
+
+impl<'a, T, C: Clone> Clone for SetOperationIter<'a, T, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        SetOperationIter {
+            left:       self.left,
+            right:      self.right,
+            operation:  self.operation,
+            comparator: self.comparator.clone(),
+        }
+    }
+}
+
+impl<'a, T: Debug, C> Debug for SetOperationIter<'a, T, C> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SetOperationIter")
+            .field("left", &self.left)
+            .field("right", &self.right)
+            .field("operation", &self.operation)
+            .finish()
+    }
+}
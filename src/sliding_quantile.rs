@@ -0,0 +1,282 @@
+//! A tracker of the quantiles of the latest elements of a stream, such as a rolling median.
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    iter::FusedIterator,
+};
+use std::collections::{vec_deque, VecDeque};
+
+use crate::{
+    percentile_index, sorted_chunked_vec, Comparator, Natural, SortedChunkedVec,
+    SortedInsertBinaryBy, Total, TotalOrd,
+};
+
+/// A tracker of the quantiles of the latest `window_size` elements of a stream, such as a rolling median.
+///
+/// The elements are kept in their arrival order, to know which one leaves the window, and their sequence numbers are kept in a `SortedChunkedVec` sorted by a `Comparator` and then by the sequence numbers, so that pushing an element costs a binary insertion and a binary removal, and a quantile is read directly by its index. Elements which compare equal are told apart by their sequence numbers, so the element which leaves the window is always the oldest one, even with a comparator which only compares a part of the elements. Use `new` for `Ord` elements, or `new_total` for floating-point numbers, which are compared with `total_cmp`.
+///
+/// ```rust
+/// use sorted_insert::SlidingQuantile;
+///
+/// let mut tracker = SlidingQuantile::new(3);
+///
+/// tracker.push(5);
+/// tracker.push(1);
+/// tracker.push(3);
+///
+/// assert_eq!(Some(&3), tracker.median());
+///
+/// assert_eq!(Some(5), tracker.push(9));
+/// assert_eq!(Some(&3), tracker.median());
+/// assert_eq!(Some(&9), tracker.quantile(1.0));
+///
+/// let mut tracker = SlidingQuantile::new_total(2);
+///
+/// tracker.push(2.5);
+/// tracker.push(-1.0);
+///
+/// assert_eq!(Some(&-1.0), tracker.median());
+/// ```
+pub struct SlidingQuantile<T, C = Natural<T>> {
+    window:      VecDeque<T>,
+    /// The sequence numbers of the elements, sorted by the comparator and then by themselves.
+    sorted:      SortedChunkedVec<u64>,
+    /// The sequence number of the oldest element.
+    base:        u64,
+    window_size: usize,
+    comparator:  C,
+}
+
+impl<T: Ord> SlidingQuantile<T> {
+    /// Create an empty tracker of the latest `window_size` elements, which are compared by their `Ord` implementation.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `window_size` is zero.
+    #[inline]
+    pub fn new(window_size: usize) -> Self {
+        Self::with_comparator(window_size, Natural::new())
+    }
+}
+
+impl<T: TotalOrd> SlidingQuantile<T, Total<T>> {
+    /// Create an empty tracker of the latest `window_size` elements, which are compared by their `TotalOrd` implementation, such as `total_cmp` of floating-point numbers.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `window_size` is zero.
+    #[inline]
+    pub fn new_total(window_size: usize) -> Self {
+        Self::with_comparator(window_size, Total::new())
+    }
+}
+
+impl<T, C: Comparator<T>> SlidingQuantile<T, C> {
+    /// Create an empty tracker of the latest `window_size` elements, which are compared by a specific `Comparator`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `window_size` is zero.
+    #[inline]
+    pub fn with_comparator(window_size: usize, comparator: C) -> Self {
+        assert!(window_size > 0, "the window size must not be zero");
+
+        SlidingQuantile {
+            window: VecDeque::new(),
+            sorted: SortedChunkedVec::new(),
+            base: 0,
+            window_size,
+            comparator,
+        }
+    }
+
+    /// Push an element into the window. If the window is full, the oldest element leaves it and is returned.
+    pub fn push(&mut self, element: T) -> Option<T> {
+        let evicted = if self.window.len() == self.window_size { self.pop_oldest() } else { None };
+
+        let seq = self.base + self.window.len() as u64;
+
+        self.window.push_back(element);
+
+        let (window, base, comparator) = (&self.window, self.base, &self.comparator);
+
+        self.sorted
+            .sorted_insert_binary_by(seq, |a, b| compare_seqs(window, base, comparator, *a, *b));
+
+        evicted
+    }
+
+    /// Remove the oldest element from the window and return it.
+    pub fn pop_oldest(&mut self) -> Option<T> {
+        let (window, base, comparator) = (&self.window, self.base, &self.comparator);
+
+        let oldest = window.front()?;
+
+        match self.sorted.binary_search_by(|seq| {
+            comparator.compare(&window[(*seq - base) as usize], oldest).then(seq.cmp(&base))
+        }) {
+            Ok(index) => {
+                self.sorted.remove(index);
+            },
+            Err(_) => unreachable!("the sequence number must be in the sorted sequence numbers"),
+        }
+
+        self.base += 1;
+
+        self.window.pop_front()
+    }
+
+    /// Return the element at the `q`-quantile (from 0 to 1) of the window, by using the nearest-rank method. Return `None` if the window is empty.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `q` is not between 0 and 1.
+    #[inline]
+    pub fn quantile(&self, q: f64) -> Option<&T> {
+        assert!((0.0..=1.0).contains(&q), "the quantile must be between 0 and 1");
+
+        let index = percentile_index(self.sorted.len(), q * 100.0)?;
+
+        self.sorted_get(index)
+    }
+
+    /// Return the median of the window. If the number of elements is even, return the lower one of the two middle elements. Return `None` if the window is empty.
+    #[inline]
+    pub fn median(&self) -> Option<&T> {
+        let len = self.sorted.len();
+
+        if len == 0 {
+            None
+        } else {
+            self.sorted_get((len - 1) / 2)
+        }
+    }
+}
+
+impl<T, C> SlidingQuantile<T, C> {
+    /// Return the maximum number of elements in the window.
+    #[inline]
+    pub fn window_size(&self) -> usize {
+        self.window_size
+    }
+
+    /// Return the number of elements in the window.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    /// Return `true` if the window contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+
+    /// Return `true` if the window contains `window_size` elements.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.window.len() == self.window_size
+    }
+
+    /// Remove all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.window.clear();
+        self.sorted.clear();
+        self.base = 0;
+    }
+
+    /// Return the smallest element of the window.
+    #[inline]
+    pub fn min(&self) -> Option<&T> {
+        self.sorted_get(0)
+    }
+
+    /// Return the largest element of the window.
+    #[inline]
+    pub fn max(&self) -> Option<&T> {
+        self.sorted_get(self.sorted.len().checked_sub(1)?)
+    }
+
+    /// Iterate over the elements of the window in their arrival order.
+    #[inline]
+    pub fn iter(&self) -> vec_deque::Iter<'_, T> {
+        self.window.iter()
+    }
+
+    /// Iterate over the elements of the window in sorted order. Elements which compare equal are yielded in their arrival order.
+    #[inline]
+    pub fn iter_sorted(&self) -> IterSorted<'_, T> {
+        IterSorted {
+            window: &self.window, seqs: self.sorted.iter(), base: self.base
+        }
+    }
+
+    /// Return the element at `index` in sorted order.
+    #[inline]
+    fn sorted_get(&self, index: usize) -> Option<&T> {
+        let seq = self.sorted.get(index)?;
+
+        Some(&self.window[(*seq - self.base) as usize])
+    }
+}
+
+/// Compare two elements in the window by their sequence numbers, by the comparator and then by the sequence numbers.
+#[inline]
+fn compare_seqs<T, C: Comparator<T>>(
+    window: &VecDeque<T>,
+    base: u64,
+    comparator: &C,
+    a: u64,
+    b: u64,
+) -> Ordering {
+    comparator.compare(&window[(a - base) as usize], &window[(b - base) as usize]).then(a.cmp(&b))
+}
+
+impl<T: Debug, C> Debug for SlidingQuantile<T, C> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SlidingQuantile")
+            .field("window", &self.window)
+            .field("window_size", &self.window_size)
+            .finish()
+    }
+}
+
+/// An iterator over the elements of a `SlidingQuantile` in sorted order.
+#[derive(Debug, Clone)]
+pub struct IterSorted<'a, T> {
+    window: &'a VecDeque<T>,
+    seqs:   sorted_chunked_vec::Iter<'a, u64>,
+    base:   u64,
+}
+
+impl<'a, T> Iterator for IterSorted<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let seq = self.seqs.next()?;
+
+        Some(&self.window[(*seq - self.base) as usize])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.seqs.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterSorted<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let seq = self.seqs.next_back()?;
+
+        Some(&self.window[(*seq - self.base) as usize])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterSorted<'a, T> {}
+
+impl<'a, T> FusedIterator for IterSorted<'a, T> {}
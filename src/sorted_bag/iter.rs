@@ -0,0 +1,137 @@
+use core::{iter::FusedIterator, slice};
+
+/// An iterator over the elements of a `SortedBag`, in ascending order. Each element is yielded as many times as it is counted.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    runs:      slice::Iter<'a, (T, usize)>,
+    front:     Option<(&'a T, usize)>,
+    back:      Option<(&'a T, usize)>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    #[inline]
+    pub(crate) fn new(runs: slice::Iter<'a, (T, usize)>, len: usize) -> Self {
+        Iter {
+            runs,
+            front: None,
+            back: None,
+            remaining: len,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((element, count)) = self.front.as_mut() {
+                if *count > 0 {
+                    *count -= 1;
+                    self.remaining -= 1;
+
+                    return Some(*element);
+                }
+            }
+
+            match self.runs.next() {
+                Some((element, count)) => self.front = Some((element, *count)),
+                None => {
+                    // the rest of the elements are in the run taken by `next_back`
+                    let (element, count) = self.back.as_mut()?;
+
+                    if *count == 0 {
+                        return None;
+                    }
+
+                    *count -= 1;
+                    self.remaining -= 1;
+
+                    return Some(*element);
+                },
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((element, count)) = self.back.as_mut() {
+                if *count > 0 {
+                    *count -= 1;
+                    self.remaining -= 1;
+
+                    return Some(*element);
+                }
+            }
+
+            match self.runs.next_back() {
+                Some((element, count)) => self.back = Some((element, *count)),
+                None => {
+                    // the rest of the elements are in the run taken by `next`
+                    let (element, count) = self.front.as_mut()?;
+
+                    if *count == 0 {
+                        return None;
+                    }
+
+                    *count -= 1;
+                    self.remaining -= 1;
+
+                    return Some(*element);
+                },
+            }
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> FusedIterator for Iter<'a, T> {}
+
+/// An iterator over the distinct elements of a `SortedBag` and their counts, in ascending order.
+#[derive(Debug, Clone)]
+pub struct Runs<'a, T> {
+    inner: slice::Iter<'a, (T, usize)>,
+}
+
+impl<'a, T> Runs<'a, T> {
+    #[inline]
+    pub(crate) fn new(inner: slice::Iter<'a, (T, usize)>) -> Self {
+        Runs {
+            inner,
+        }
+    }
+}
+
+impl<'a, T> Iterator for Runs<'a, T> {
+    type Item = (&'a T, usize);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(element, count)| (element, *count))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Runs<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(element, count)| (element, *count))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Runs<'a, T> {}
+
+impl<'a, T> FusedIterator for Runs<'a, T> {}
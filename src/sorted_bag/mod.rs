@@ -0,0 +1,331 @@
+//! A sorted multiset which stores each distinct element once along with its count.
+
+mod iter;
+#[cfg(feature = "serde")]
+mod serde;
+
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug, Formatter},
+};
+
+pub use iter::*;
+
+use crate::{fenwick::Fenwick, SortedInsertBinaryBy, SortedInsertComparator};
+
+/// A sorted multiset stored as a `Vec` of `(T, count)` runs in ascending order.
+///
+/// Inserting an element which is already in the bag only increments its count, so duplicates cost no extra space.
+///
+/// ## Complexity
+///
+/// With `d` distinct elements, `count`, `contains`, `rank`, `select` and incrementing or decrementing the count of an existing element cost `O(log d)`, because the counts of the runs are also kept in a Fenwick tree. Inserting a new distinct element or removing the last copy of one shifts the runs and rebuilds the tree, so it costs `O(d)`.
+///
+/// ```rust
+/// use sorted_insert::SortedBag;
+///
+/// let mut bag = SortedBag::new();
+///
+/// bag.insert(3);
+/// bag.insert(1);
+/// bag.insert(3);
+///
+/// assert_eq!(3, bag.len());
+/// assert_eq!(2, bag.count(&3));
+/// assert_eq!(Some(&3), bag.select(1));
+/// assert_eq!(vec![&1, &3, &3], bag.iter().collect::<Vec<_>>());
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SortedBag<T> {
+    runs:   Vec<(T, usize)>,
+    len:    usize,
+    counts: Fenwick,
+}
+
+impl<T> SortedBag<T> {
+    /// Create an empty bag.
+    #[inline]
+    pub const fn new() -> Self {
+        SortedBag {
+            runs: Vec::new(), len: 0, counts: Fenwick::new()
+        }
+    }
+
+    /// Create an empty bag with space for at least `capacity` distinct elements.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        SortedBag {
+            runs: Vec::with_capacity(capacity), len: 0, counts: Fenwick::new()
+        }
+    }
+
+    /// Return the number of elements in this bag, counting duplicates.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return the number of distinct elements in this bag.
+    #[inline]
+    pub fn distinct_len(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Return `true` if this bag contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Remove all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.runs.clear();
+        self.len = 0;
+        self.counts.clear();
+    }
+
+    /// Extract the underlying `(element, count)` runs as a slice in ascending order.
+    #[inline]
+    pub fn as_slice(&self) -> &[(T, usize)] {
+        self.runs.as_slice()
+    }
+
+    /// Convert this bag into its underlying `Vec` of `(element, count)` runs in ascending order.
+    #[inline]
+    pub fn into_vec(self) -> Vec<(T, usize)> {
+        self.runs
+    }
+
+    /// Return the smallest element.
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.runs.first().map(|(element, _)| element)
+    }
+
+    /// Return the largest element.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.runs.last().map(|(element, _)| element)
+    }
+
+    /// Return the element at the position `index` as if all the elements, including duplicates, were laid out in ascending order.
+    #[inline]
+    pub fn select(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        Some(&self.runs[self.counts.select(index)].0)
+    }
+
+    /// Iterate over the elements in ascending order. Each element is yielded as many times as it is counted.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.runs.iter(), self.len)
+    }
+
+    /// Iterate over the distinct elements and their counts in ascending order.
+    #[inline]
+    pub fn runs(&self) -> Runs<'_, T> {
+        Runs::new(self.runs.iter())
+    }
+}
+
+impl<T: Ord> SortedBag<T> {
+    #[inline]
+    fn search<Q: ?Sized + Ord>(&self, element: &Q) -> Result<usize, usize>
+    where
+        T: Borrow<Q>, {
+        self.runs.sorted_binary_search_by(|(e, _)| e.borrow().cmp(element))
+    }
+
+    /// Rebuild the prefix counts after the runs are shifted.
+    #[inline]
+    fn rebuild_counts(&mut self) {
+        self.counts = Fenwick::build(self.runs.iter().map(|(_, count)| *count));
+    }
+
+    /// Insert an element into this bag and return its count after the insertion.
+    #[inline]
+    pub fn insert(&mut self, element: T) -> usize {
+        self.insert_many(element, 1)
+    }
+
+    /// Insert `n` copies of an element into this bag and return its count after the insertion.
+    pub fn insert_many(&mut self, element: T, n: usize) -> usize {
+        let index = self.runs.get_sorted_insert_index_binary_by(|(e, _)| e.cmp(&element));
+
+        // the binary search returns the index after the run of the element, if there is one
+        if index > 0 && self.runs[index - 1].0 == element {
+            let index = index - 1;
+
+            self.runs[index].1 += n;
+            self.len += n;
+            self.counts.add(index, n);
+
+            self.runs[index].1
+        } else {
+            if n > 0 {
+                self.runs.insert(index, (element, n));
+                self.len += n;
+                self.rebuild_counts();
+            }
+
+            n
+        }
+    }
+
+    /// Remove one copy of an element from this bag. Return `true` if the element was present.
+    #[inline]
+    pub fn remove<Q: ?Sized + Ord>(&mut self, element: &Q) -> bool
+    where
+        T: Borrow<Q>, {
+        self.remove_many(element, 1) > 0
+    }
+
+    /// Remove up to `n` copies of an element from this bag and return how many copies were removed.
+    pub fn remove_many<Q: ?Sized + Ord>(&mut self, element: &Q, n: usize) -> usize
+    where
+        T: Borrow<Q>, {
+        match self.search(element) {
+            Ok(index) => {
+                let count = &mut self.runs[index].1;
+
+                if *count > n {
+                    *count -= n;
+                    self.len -= n;
+                    self.counts.sub(index, n);
+
+                    n
+                } else {
+                    let (_, count) = self.runs.remove(index);
+
+                    self.len -= count;
+                    self.rebuild_counts();
+
+                    count
+                }
+            },
+            Err(_) => 0,
+        }
+    }
+
+    /// Remove all copies of an element from this bag and return how many copies were removed.
+    #[inline]
+    pub fn remove_all<Q: ?Sized + Ord>(&mut self, element: &Q) -> usize
+    where
+        T: Borrow<Q>, {
+        self.remove_many(element, usize::MAX)
+    }
+
+    /// Return how many copies of an element are in this bag.
+    #[inline]
+    pub fn count<Q: ?Sized + Ord>(&self, element: &Q) -> usize
+    where
+        T: Borrow<Q>, {
+        match self.search(element) {
+            Ok(index) => self.runs[index].1,
+            Err(_) => 0,
+        }
+    }
+
+    /// Return `true` if this bag contains at least one copy of an element.
+    #[inline]
+    pub fn contains<Q: ?Sized + Ord>(&self, element: &Q) -> bool
+    where
+        T: Borrow<Q>, {
+        self.search(element).is_ok()
+    }
+
+    /// Return the number of elements in this bag which are less than the given element, counting duplicates. This is the position where the first copy of the element is (or would be) when all the elements are laid out in ascending order.
+    #[inline]
+    pub fn rank<Q: ?Sized + Ord>(&self, element: &Q) -> usize
+    where
+        T: Borrow<Q>, {
+        let end = match self.search(element) {
+            Ok(index) | Err(index) => index,
+        };
+
+        self.counts.prefix_sum(end)
+    }
+}
+
+impl<T> Default for SortedBag<T> {
+    #[inline]
+    fn default() -> Self {
+        SortedBag::new()
+    }
+}
+
+impl<T: Debug> Debug for SortedBag<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedBag<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bag = SortedBag::new();
+
+        bag.extend(iter);
+
+        bag
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedBag<T> {
+    /// Insert elements into this bag in bulk by sorting them and merging their runs with the existing runs.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut elements: Vec<T> = iter.into_iter().collect();
+
+        if elements.is_empty() {
+            return;
+        }
+
+        elements.sort_unstable();
+
+        self.len += elements.len();
+
+        let mut runs: Vec<(T, usize)> = Vec::with_capacity(self.runs.len() + elements.len());
+        let mut existing = core::mem::take(&mut self.runs).into_iter().peekable();
+
+        for element in elements {
+            match runs.last_mut() {
+                Some((last, count)) if *last == element => {
+                    *count += 1;
+
+                    continue;
+                },
+                _ => (),
+            }
+
+            while let Some(run) = existing.next_if(|(e, _)| *e < element) {
+                runs.push(run);
+            }
+
+            match existing.next_if(|(e, _)| *e == element) {
+                Some((e, count)) => runs.push((e, count + 1)),
+                None => runs.push((element, 1)),
+            }
+        }
+
+        runs.extend(existing);
+
+        self.runs = runs;
+        self.rebuild_counts();
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SortedBag<T> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,69 @@
+use alloc::vec::Vec;
+
+use ::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Fenwick, SortedBag};
+use crate::serde::DeserializeResorted;
+
+impl<T: Serialize> Serialize for SortedBag<T> {
+    /// Serialize this bag as a sequence of `(element, count)` runs in ascending order.
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.runs())
+    }
+}
+
+impl<'de, T: Ord + Deserialize<'de>> Deserialize<'de> for SortedBag<T> {
+    /// Deserialize a sequence of `(element, count)` runs whose elements are in ascending order without duplicates and whose counts are not zero. Return an error if they are not.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let runs = Vec::<(T, usize)>::deserialize(deserializer)?;
+
+        if let Some(index) = runs.windows(2).position(|w| w[0].0 >= w[1].0) {
+            return Err(D::Error::custom(format_args!(
+                "the element at index {} is not less than the element at index {}",
+                index,
+                index + 1
+            )));
+        }
+
+        if let Some(index) = runs.iter().position(|(_, count)| *count == 0) {
+            return Err(D::Error::custom(format_args!("the count at index {} is zero", index)));
+        }
+
+        let len = runs.iter().try_fold(0usize, |len, (_, count)| len.checked_add(*count));
+
+        match len {
+            Some(len) => {
+                let mut bag = SortedBag {
+                    runs,
+                    len,
+                    counts: Fenwick::new(),
+                };
+
+                bag.rebuild_counts();
+
+                Ok(bag)
+            },
+            None => Err(D::Error::custom("the total count overflows")),
+        }
+    }
+}
+
+impl<'de, T: Ord + Deserialize<'de>> DeserializeResorted<'de> for SortedBag<T> {
+    /// Deserialize a sequence of `(element, count)` runs in any order. The counts of equal elements are added up and the runs whose counts are zero are dropped.
+    fn deserialize_resorted<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let runs = Vec::<(T, usize)>::deserialize(deserializer)?;
+
+        let mut bag = SortedBag::with_capacity(runs.len());
+
+        for (element, count) in runs {
+            if bag.len.checked_add(count).is_none() {
+                return Err(D::Error::custom("the total count overflows"));
+            }
+
+            bag.insert_many(element, count);
+        }
+
+        Ok(bag)
+    }
+}
@@ -0,0 +1,111 @@
+use alloc::vec::{self, Vec};
+use core::{iter::FusedIterator, slice};
+
+macro_rules! impl_iterator {
+    ($name:ident<$($lt:lifetime,)? T>, $item:ty) => {
+        impl<$($lt,)? T> Iterator for $name<$($lt,)? T> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                loop {
+                    if let Some(element) = self.front.next() {
+                        self.remaining -= 1;
+
+                        return Some(element);
+                    }
+
+                    match self.chunks.next() {
+                        Some(chunk) => self.front = chunk.into_iter(),
+                        None => {
+                            // the rest of the elements are in the chunk taken by `next_back`
+                            let element = self.back.next()?;
+
+                            self.remaining -= 1;
+
+                            return Some(element);
+                        },
+                    }
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.remaining, Some(self.remaining))
+            }
+        }
+
+        impl<$($lt,)? T> DoubleEndedIterator for $name<$($lt,)? T> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                loop {
+                    if let Some(element) = self.back.next_back() {
+                        self.remaining -= 1;
+
+                        return Some(element);
+                    }
+
+                    match self.chunks.next_back() {
+                        Some(chunk) => self.back = chunk.into_iter(),
+                        None => {
+                            // the rest of the elements are in the chunk taken by `next`
+                            let element = self.front.next_back()?;
+
+                            self.remaining -= 1;
+
+                            return Some(element);
+                        },
+                    }
+                }
+            }
+        }
+
+        impl<$($lt,)? T> ExactSizeIterator for $name<$($lt,)? T> {}
+
+        impl<$($lt,)? T> FusedIterator for $name<$($lt,)? T> {}
+    };
+}
+
+/// An iterator over the elements of a `SortedChunkedVec`, in order.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    chunks:    slice::Iter<'a, Vec<T>>,
+    front:     slice::Iter<'a, T>,
+    back:      slice::Iter<'a, T>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    #[inline]
+    pub(crate) fn new(chunks: slice::Iter<'a, Vec<T>>, len: usize) -> Self {
+        Iter {
+            chunks,
+            front: [].iter(),
+            back: [].iter(),
+            remaining: len,
+        }
+    }
+}
+
+impl_iterator!(Iter<'a, T>, &'a T);
+
+/// An owning iterator over the elements of a `SortedChunkedVec`, in order.
+#[derive(Debug)]
+pub struct IntoIter<T> {
+    chunks:    vec::IntoIter<Vec<T>>,
+    front:     vec::IntoIter<T>,
+    back:      vec::IntoIter<T>,
+    remaining: usize,
+}
+
+impl<T> IntoIter<T> {
+    #[inline]
+    pub(crate) fn new(chunks: vec::IntoIter<Vec<T>>, len: usize) -> Self {
+        IntoIter {
+            chunks,
+            front: Vec::new().into_iter(),
+            back: Vec::new().into_iter(),
+            remaining: len,
+        }
+    }
+}
+
+impl_iterator!(IntoIter<T>, T);
@@ -0,0 +1,528 @@
+//! A sorted sequence stored as a `Vec` of bounded sorted chunks.
+
+mod iter;
+#[cfg(feature = "serde")]
+mod serde;
+
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    ops::Index,
+};
+
+pub use iter::*;
+
+use crate::{fenwick::Fenwick, merge, search};
+
+/// The default maximum number of elements in a chunk.
+pub const DEFAULT_CHUNK_SIZE: usize = 1024;
+
+/// A sorted sequence stored as a `Vec` of sorted chunks, each of which holds at most `chunk_size` elements.
+///
+/// The lengths of the chunks are kept in a Fenwick tree, so the chunk of an index is found in `O(log n)`, and accessing an element by its index costs `O(log n)`. Inserting or removing an element only shifts the elements of one chunk instead of all the following elements, so it costs `O(B + log n)` where `B` is the chunk size. A chunk which grows over `B` elements is split into two halves, and a chunk which shrinks under `B / 4` elements is merged with a neighbour when they fit in one chunk. Splitting or merging chunks shifts the chunk pointers and rebuilds the tree, which costs `O(n / B)` but happens at most once every `B / 4` insertions or removals in a chunk.
+///
+/// It implements the insertion, entry, merge, bulk, in-place set operation and rank traits which `Vec` implements, so code which inserts into a large `Vec` can switch over without changing the call sites. Interpolation search and the set operations which read a slice are not available. Merging, the bulk operations and the in-place set operations rebuild the chunks, so they cost `O(n)` like their `Vec` counterparts.
+///
+/// ```rust
+/// use sorted_insert::{SortedChunkedVec, SortedInsertBinary};
+///
+/// let mut v = SortedChunkedVec::with_chunk_size(4);
+///
+/// for e in [5, 1, 4, 2, 3, 6] {
+///     v.sorted_insert_asc_binary(e);
+/// }
+///
+/// assert_eq!(6, v.len());
+/// assert_eq!(Ok(2), v.binary_search(&3));
+/// assert_eq!(vec![1, 2, 3, 4, 5, 6], v.iter().copied().collect::<Vec<i32>>());
+/// ```
+#[derive(Clone)]
+pub struct SortedChunkedVec<T> {
+    chunks:     Vec<Vec<T>>,
+    /// The lengths of the chunks.
+    lens:       Fenwick,
+    len:        usize,
+    chunk_size: usize,
+}
+
+impl<T> SortedChunkedVec<T> {
+    /// Create an empty sequence with the default chunk size, which is `DEFAULT_CHUNK_SIZE`.
+    #[inline]
+    pub const fn new() -> Self {
+        SortedChunkedVec {
+            chunks:     Vec::new(),
+            lens:       Fenwick::new(),
+            len:        0,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Create an empty sequence whose chunks hold at most `chunk_size` elements.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if `chunk_size` is less than 2.
+    #[inline]
+    pub fn with_chunk_size(chunk_size: usize) -> Self {
+        assert!(chunk_size >= 2, "the chunk size must be at least 2");
+
+        SortedChunkedVec {
+            chunks: Vec::new(),
+            lens: Fenwick::new(),
+            len: 0,
+            chunk_size,
+        }
+    }
+
+    /// Return the maximum number of elements in a chunk.
+    #[inline]
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Return the number of elements in this sequence.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if this sequence contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return the number of chunks.
+    #[inline]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Remove all elements.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.lens.clear();
+        self.len = 0;
+    }
+
+    /// Return the element at `index`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let (chunk, offset) = self.locate(index);
+
+        Some(&self.chunks[chunk][offset])
+    }
+
+    /// Return the first element.
+    #[inline]
+    pub fn first(&self) -> Option<&T> {
+        self.chunks.first().and_then(|chunk| chunk.first())
+    }
+
+    /// Return the last element.
+    #[inline]
+    pub fn last(&self) -> Option<&T> {
+        self.chunks.last().and_then(|chunk| chunk.last())
+    }
+
+    /// Remove the element at `index` and return it.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "the removal index (is {}) should be < len (is {})",
+            index,
+            self.len
+        );
+
+        let (chunk, offset) = self.locate(index);
+
+        let element = self.chunks[chunk].remove(offset);
+
+        self.len -= 1;
+        self.lens.sub(chunk, 1);
+
+        self.rebalance(chunk);
+
+        element
+    }
+
+    /// Remove the last element and return it.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.remove(self.len - 1))
+        }
+    }
+
+    /// Search this sequence with a comparator function, like `slice::binary_search_by`. Return `Ok` with the index of a matching element, or `Err` with the index where a matching element could be inserted.
+    pub fn binary_search_by<F: FnMut(&T) -> Ordering>(&self, mut f: F) -> Result<usize, usize> {
+        // the chunks whose last elements are ordered before the target are entirely before it
+        let chunk =
+            self.chunks.partition_point(|chunk| f(&chunk[chunk.len() - 1]) == Ordering::Less);
+
+        if chunk == self.chunks.len() {
+            return Err(self.len);
+        }
+
+        let start = self.chunk_start(chunk);
+
+        match self.chunks[chunk].binary_search_by(f) {
+            Ok(offset) => Ok(start + offset),
+            Err(offset) => Err(start + offset),
+        }
+    }
+
+    /// Search this sequence with a key extraction function, like `slice::binary_search_by_key`.
+    #[inline]
+    pub fn binary_search_by_key<B: Ord, F: FnMut(&T) -> B>(
+        &self,
+        b: &B,
+        mut f: F,
+    ) -> Result<usize, usize> {
+        self.binary_search_by(|e| f(e).cmp(b))
+    }
+
+    /// Return the index of the first element for which `pred` returns `false`, like `slice::partition_point`.
+    pub fn partition_point<P: FnMut(&T) -> bool>(&self, mut pred: P) -> usize {
+        let chunk = self.chunks.partition_point(|chunk| pred(&chunk[chunk.len() - 1]));
+
+        if chunk == self.chunks.len() {
+            return self.len;
+        }
+
+        self.chunk_start(chunk) + self.chunks[chunk].partition_point(pred)
+    }
+
+    /// Iterate over the elements in order.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter::new(self.chunks.iter(), self.len)
+    }
+
+    /// Convert this sequence into a `Vec`.
+    #[inline]
+    pub fn into_vec(self) -> Vec<T> {
+        let mut v = Vec::with_capacity(self.len);
+
+        for mut chunk in self.chunks {
+            v.append(&mut chunk);
+        }
+
+        v
+    }
+
+    /// Split a sorted `Vec` into chunks, filling them to the half of the chunk size so that later insertions do not split them right away.
+    pub(crate) fn from_sorted_vec_unchecked(v: Vec<T>, chunk_size: usize) -> Self {
+        let fill = chunk_size / 2;
+        let len = v.len();
+
+        let mut chunks = Vec::with_capacity(len / fill + 1);
+        let mut iter = v.into_iter();
+
+        while iter.len() > 0 {
+            let mut chunk = Vec::with_capacity(chunk_size);
+
+            chunk.extend(iter.by_ref().take(fill));
+
+            chunks.push(chunk);
+        }
+
+        SortedChunkedVec {
+            lens: Fenwick::build(chunks.iter().map(Vec::len)),
+            chunks,
+            len,
+            chunk_size,
+        }
+    }
+
+    /// Return the index of the first element of a chunk.
+    #[inline]
+    fn chunk_start(&self, chunk: usize) -> usize {
+        self.lens.prefix_sum(chunk)
+    }
+
+    /// Return the chunk and the offset in the chunk of an element. `index` can be `len` to locate the end of the last chunk.
+    #[inline]
+    fn locate(&self, index: usize) -> (usize, usize) {
+        if index >= self.len {
+            let last = self.chunks.len() - 1;
+
+            return (last, self.chunks[last].len());
+        }
+
+        let chunk = self.lens.select(index);
+
+        (chunk, index - self.chunk_start(chunk))
+    }
+
+    /// Rebuild the lengths of the chunks after the chunks are split, merged or removed.
+    #[inline]
+    fn rebuild_lens(&mut self) {
+        self.lens = Fenwick::build(self.chunks.iter().map(Vec::len));
+    }
+
+    /// Run `g` on the elements as one `Vec`, then split them into chunks again. It costs `O(n)`, like the operations of `Vec` which move most of the elements.
+    pub(crate) fn with_vec<R, G: FnOnce(&mut Vec<T>) -> R>(&mut self, g: G) -> R {
+        let chunk_size = self.chunk_size;
+        let mut v =
+            core::mem::replace(self, SortedChunkedVec::with_chunk_size(chunk_size)).into_vec();
+
+        let result = g(&mut v);
+
+        *self = SortedChunkedVec::from_sorted_vec_unchecked(v, chunk_size);
+
+        result
+    }
+
+    /// Return the index after the last element for which `f` does not return `Greater`. Start from the chunk of `hint`, step over whole chunks by comparing their first elements, and then gallop from `hint` in the chunk where the index is.
+    pub(crate) fn gallop_by<F: FnMut(&T) -> Ordering>(&self, hint: usize, mut f: F) -> usize {
+        if self.chunks.is_empty() {
+            return 0;
+        }
+
+        let (mut chunk, mut offset) = self.locate(hint.min(self.len));
+
+        while chunk > 0 && f(&self.chunks[chunk][0]) == Ordering::Greater {
+            chunk -= 1;
+            offset = self.chunks[chunk].len();
+        }
+
+        while chunk + 1 < self.chunks.len() && f(&self.chunks[chunk + 1][0]) != Ordering::Greater {
+            chunk += 1;
+            offset = 0;
+        }
+
+        self.chunk_start(chunk) + search::gallop_by(&self.chunks[chunk], offset, f)
+    }
+
+    /// Split the element at `index` from its neighbours, which may be in the neighbouring chunks.
+    pub(crate) fn neighbors_mut(&mut self, index: usize) -> (Option<&T>, &mut T, Option<&T>) {
+        let (chunk, offset) = self.locate(index);
+
+        let (before, rest) = self.chunks.split_at_mut(chunk);
+        let (current, after) = rest.split_first_mut().unwrap();
+        let (left, right) = current.split_at_mut(offset);
+        let (element, right) = right.split_first_mut().unwrap();
+
+        let predecessor = left.last().or_else(|| before.last().and_then(|chunk| chunk.last()));
+        let successor = right.first().or_else(|| after.first().and_then(|chunk| chunk.first()));
+
+        (predecessor, element, successor)
+    }
+
+    /// Insert an element at `index`, splitting the chunk if it becomes too large.
+    pub(crate) fn insert(&mut self, index: usize, element: T) {
+        assert!(
+            index <= self.len,
+            "the insertion index (is {}) should be <= len (is {})",
+            index,
+            self.len
+        );
+
+        if self.chunks.is_empty() {
+            let mut chunk = Vec::with_capacity(self.chunk_size);
+
+            chunk.push(element);
+
+            self.chunks.push(chunk);
+            self.len = 1;
+            self.rebuild_lens();
+
+            return;
+        }
+
+        let (chunk, offset) = self.locate(index);
+
+        self.chunks[chunk].insert(offset, element);
+        self.len += 1;
+
+        if self.chunks[chunk].len() > self.chunk_size {
+            let half = self.chunks[chunk].len() / 2;
+            let mut second = Vec::with_capacity(self.chunk_size);
+
+            second.extend(self.chunks[chunk].drain(half..));
+
+            self.chunks.insert(chunk + 1, second);
+            self.rebuild_lens();
+        } else {
+            self.lens.add(chunk, 1);
+        }
+    }
+
+    /// Remove the chunk if it is empty, or merge it with a neighbour if it is small and they fit in one chunk.
+    fn rebalance(&mut self, chunk: usize) {
+        let len = self.chunks[chunk].len();
+
+        if len == 0 {
+            self.chunks.remove(chunk);
+            self.rebuild_lens();
+        } else if len < self.chunk_size / 4 {
+            let (first, second) = if chunk + 1 < self.chunks.len() {
+                (chunk, chunk + 1)
+            } else if chunk > 0 {
+                (chunk - 1, chunk)
+            } else {
+                return;
+            };
+
+            if self.chunks[first].len() + self.chunks[second].len() <= self.chunk_size {
+                let mut removed = self.chunks.remove(second);
+
+                self.chunks[first].append(&mut removed);
+                self.rebuild_lens();
+            }
+        }
+    }
+}
+
+impl<T: Ord> SortedChunkedVec<T> {
+    /// Create a sequence from a `Vec` of elements by sorting them and splitting them into chunks of the default chunk size.
+    #[inline]
+    pub fn from_vec(v: Vec<T>) -> Self {
+        SortedChunkedVec::from_vec_with_chunk_size(v, DEFAULT_CHUNK_SIZE)
+    }
+
+    /// Create a sequence from a `Vec` of elements by sorting them and splitting them into chunks which hold at most `chunk_size` elements.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if `chunk_size` is less than 2.
+    #[inline]
+    pub fn from_vec_with_chunk_size(mut v: Vec<T>, chunk_size: usize) -> Self {
+        assert!(chunk_size >= 2, "the chunk size must be at least 2");
+
+        v.sort();
+
+        SortedChunkedVec::from_sorted_vec_unchecked(v, chunk_size)
+    }
+
+    /// Search this sequence for an element, like `slice::binary_search`.
+    #[inline]
+    pub fn binary_search(&self, element: &T) -> Result<usize, usize> {
+        self.binary_search_by(|e| e.cmp(element))
+    }
+
+    /// Return `true` if this sequence contains an element equal to `element`.
+    #[inline]
+    pub fn contains(&self, element: &T) -> bool {
+        self.binary_search(element).is_ok()
+    }
+}
+
+impl<T> Default for SortedChunkedVec<T> {
+    #[inline]
+    fn default() -> Self {
+        SortedChunkedVec::new()
+    }
+}
+
+impl<T: Debug> Debug for SortedChunkedVec<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for SortedChunkedVec<T> {
+    /// Compare the elements, regardless of how they are split into chunks.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for SortedChunkedVec<T> {}
+
+impl<T: Hash> Hash for SortedChunkedVec<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+
+        for element in self.iter() {
+            element.hash(state);
+        }
+    }
+}
+
+impl<T> Index<usize> for SortedChunkedVec<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(element) => element,
+            None => {
+                panic!("index out of bounds: the len is {} but the index is {}", self.len, index)
+            },
+        }
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for SortedChunkedVec<T> {
+    #[inline]
+    fn from(v: Vec<T>) -> Self {
+        SortedChunkedVec::from_vec(v)
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SortedChunkedVec<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        SortedChunkedVec::from_vec(iter.into_iter().collect())
+    }
+}
+
+impl<T: Ord> Extend<T> for SortedChunkedVec<T> {
+    /// Insert elements into this sequence in bulk. Only the new elements are sorted, and then they are merged into the existing elements after their equal elements, so an extension with `m` elements costs `O(n + m log m)`.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let mut elements: Vec<T> = iter.into_iter().collect();
+
+        if elements.is_empty() {
+            return;
+        }
+
+        elements.sort();
+
+        self.with_vec(|v| merge::merge_vec_by(v, elements, false, T::cmp))
+    }
+}
+
+impl<T> From<SortedChunkedVec<T>> for Vec<T> {
+    #[inline]
+    fn from(v: SortedChunkedVec<T>) -> Self {
+        v.into_vec()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SortedChunkedVec<T> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> IntoIterator for SortedChunkedVec<T> {
+    type IntoIter = IntoIter<T>;
+    type Item = T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.chunks.into_iter(), self.len)
+    }
+}
@@ -0,0 +1,35 @@
+use alloc::vec::Vec;
+
+use ::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{SortedChunkedVec, DEFAULT_CHUNK_SIZE};
+use crate::serde::DeserializeResorted;
+
+impl<T: Serialize> Serialize for SortedChunkedVec<T> {
+    /// Serialize the elements as a sequence, regardless of how they are split into chunks.
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Ord + Deserialize<'de>> Deserialize<'de> for SortedChunkedVec<T> {
+    /// Deserialize a sequence of elements in ascending order, with the default chunk size. Return an error if they are not in order.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let v = Vec::<T>::deserialize(deserializer)?;
+
+        crate::check_sorted_asc(&v).map_err(D::Error::custom)?;
+
+        Ok(SortedChunkedVec::from_sorted_vec_unchecked(v, DEFAULT_CHUNK_SIZE))
+    }
+}
+
+impl<'de, T: Ord + Deserialize<'de>> DeserializeResorted<'de> for SortedChunkedVec<T> {
+    /// Deserialize a sequence of elements in any order by sorting them in ascending order, with the default chunk size.
+    #[inline]
+    fn deserialize_resorted<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let v = Vec::<T>::deserialize(deserializer)?;
+
+        Ok(SortedChunkedVec::from_vec(v))
+    }
+}
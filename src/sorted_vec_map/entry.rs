@@ -0,0 +1,187 @@
+use alloc::vec::Vec;
+
+/// A view into a single entry in a `SortedVecMap`, which may either be vacant or occupied.
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+/// A view into an occupied entry in a `SortedVecMap`.
+pub struct OccupiedEntry<'a, K, V> {
+    entries: &'a mut Vec<(K, V)>,
+    index:   usize,
+}
+
+/// A view into a vacant entry in a `SortedVecMap`.
+pub struct VacantEntry<'a, K, V> {
+    entries: &'a mut Vec<(K, V)>,
+    index:   usize,
+    key:     K,
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    /// Return a reference to the key of this entry.
+    #[inline]
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Return the index of this entry in the underlying sorted entries. For a vacant entry, it is the index where the entry would be inserted.
+    #[inline]
+    pub fn index(&self) -> usize {
+        match self {
+            Entry::Occupied(entry) => entry.index(),
+            Entry::Vacant(entry) => entry.index(),
+        }
+    }
+
+    /// Ensure a value is in the entry by inserting the default if empty, and return a mutable reference to the value.
+    #[inline]
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Ensure a value is in the entry by inserting the result of the default function if empty, and return a mutable reference to the value.
+    #[inline]
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Ensure a value is in the entry by inserting the result of the default function called with the key if empty, and return a mutable reference to the value.
+    #[inline]
+    pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+
+                entry.insert(value)
+            },
+        }
+    }
+
+    /// Provide in-place mutable access to an occupied entry before any potential inserts into the map.
+    #[inline]
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Self {
+        match self {
+            Entry::Occupied(mut entry) => {
+                f(entry.get_mut());
+
+                Entry::Occupied(entry)
+            },
+            Entry::Vacant(entry) => Entry::Vacant(entry),
+        }
+    }
+}
+
+impl<'a, K, V: Default> Entry<'a, K, V> {
+    /// Ensure a value is in the entry by inserting the default value if empty, and return a mutable reference to the value.
+    #[inline]
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    #[inline]
+    pub(crate) fn new(entries: &'a mut Vec<(K, V)>, index: usize) -> Self {
+        OccupiedEntry {
+            entries,
+            index,
+        }
+    }
+
+    /// Return a reference to the key of this entry.
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.entries[self.index].0
+    }
+
+    /// Return the index of this entry in the underlying sorted entries.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Return a reference to the value of this entry.
+    #[inline]
+    pub fn get(&self) -> &V {
+        &self.entries[self.index].1
+    }
+
+    /// Return a mutable reference to the value of this entry.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.entries[self.index].1
+    }
+
+    /// Convert this entry into a mutable reference to its value, bound to the lifetime of the map.
+    #[inline]
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.entries[self.index].1
+    }
+
+    /// Set the value of this entry and return the old value.
+    #[inline]
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    /// Take the value of this entry out of the map and return it.
+    #[inline]
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Take the key and the value of this entry out of the map and return them.
+    #[inline]
+    pub fn remove_entry(self) -> (K, V) {
+        self.entries.remove(self.index)
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    #[inline]
+    pub(crate) fn new(entries: &'a mut Vec<(K, V)>, index: usize, key: K) -> Self {
+        VacantEntry {
+            entries,
+            index,
+            key,
+        }
+    }
+
+    /// Return a reference to the key that would be used when inserting a value through this entry.
+    #[inline]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Return the index where the entry would be inserted in the underlying sorted entries.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Take ownership of the key.
+    #[inline]
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Insert the value with the key of this entry into the map, and return a mutable reference to the value.
+    #[inline]
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.entries.insert(self.index, (self.key, value));
+
+        &mut self.entries[self.index].1
+    }
+}
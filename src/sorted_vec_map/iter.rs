@@ -0,0 +1,79 @@
+use core::{iter::FusedIterator, slice};
+
+macro_rules! impl_iterator {
+    ($name:ident, $inner:ident, $item:ty, |$e:ident| $map:expr) => {
+        impl<'a, K, V> $name<'a, K, V> {
+            #[inline]
+            pub(crate) fn new(inner: slice::$inner<'a, (K, V)>) -> Self {
+                $name {
+                    inner,
+                }
+            }
+        }
+
+        impl<'a, K, V> Iterator for $name<'a, K, V> {
+            type Item = $item;
+
+            #[inline]
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next().map(|$e| $map)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+        }
+
+        impl<'a, K, V> DoubleEndedIterator for $name<'a, K, V> {
+            #[inline]
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back().map(|$e| $map)
+            }
+        }
+
+        impl<'a, K, V> ExactSizeIterator for $name<'a, K, V> {}
+
+        impl<'a, K, V> FusedIterator for $name<'a, K, V> {}
+    };
+}
+
+/// An iterator over the entries of a `SortedVecMap`, in ascending order by keys.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, K, V> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl_iterator!(Iter, Iter, (&'a K, &'a V), |e| (&e.0, &e.1));
+
+/// A mutable iterator over the entries of a `SortedVecMap`, in ascending order by keys.
+#[derive(Debug)]
+pub struct IterMut<'a, K, V> {
+    inner: slice::IterMut<'a, (K, V)>,
+}
+
+impl_iterator!(IterMut, IterMut, (&'a K, &'a mut V), |e| (&e.0, &mut e.1));
+
+/// An iterator over the keys of a `SortedVecMap`, in ascending order.
+#[derive(Debug, Clone)]
+pub struct Keys<'a, K, V> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl_iterator!(Keys, Iter, &'a K, |e| &e.0);
+
+/// An iterator over the values of a `SortedVecMap`, in ascending order by keys.
+#[derive(Debug, Clone)]
+pub struct Values<'a, K, V> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl_iterator!(Values, Iter, &'a V, |e| &e.1);
+
+/// A mutable iterator over the values of a `SortedVecMap`, in ascending order by keys.
+#[derive(Debug)]
+pub struct ValuesMut<'a, K, V> {
+    inner: slice::IterMut<'a, (K, V)>,
+}
+
+impl_iterator!(ValuesMut, IterMut, &'a mut V, |e| &mut e.1);
@@ -0,0 +1,465 @@
+//! A map backed by a sorted `Vec` of key-value pairs.
+
+mod entry;
+mod iter;
+#[cfg(feature = "serde")]
+mod serde;
+
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Debug, Formatter},
+    ops::{Bound, Index, RangeBounds},
+};
+
+pub use entry::*;
+pub use iter::*;
+
+use crate::{SortedInsertBinaryBy, SortedInsertComparator};
+
+/// An ordered map stored as a `Vec` of `(K, V)` pairs sorted in ascending order by keys.
+///
+/// Lookups use binary search and iteration walks a contiguous slice, which makes this a lighter alternative to `BTreeMap` for small maps. Insertions and removals shift the following entries, so they cost `O(n)`.
+///
+/// ```rust
+/// use sorted_insert::SortedVecMap;
+///
+/// let mut map = SortedVecMap::new();
+///
+/// map.insert(3, "c");
+/// map.insert(1, "a");
+/// map.insert(2, "b");
+///
+/// assert_eq!(Some(&"b"), map.get(&2));
+/// assert_eq!(
+///     vec![(&1, &"a"), (&2, &"b"), (&3, &"c")],
+///     map.iter().collect::<Vec<_>>()
+/// );
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SortedVecMap<K, V> {
+    entries: Vec<(K, V)>,
+}
+
+impl<K, V> SortedVecMap<K, V> {
+    /// Create an empty map.
+    #[inline]
+    pub const fn new() -> Self {
+        SortedVecMap {
+            entries: Vec::new()
+        }
+    }
+
+    /// Create an empty map with space for at least `capacity` entries.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        SortedVecMap {
+            entries: Vec::with_capacity(capacity)
+        }
+    }
+
+    /// Return the number of entries in this map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return `true` if this map contains no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Return the number of entries this map can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.entries.capacity()
+    }
+
+    /// Remove all entries.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Extract the underlying entries as a slice sorted by keys.
+    #[inline]
+    pub fn as_slice(&self) -> &[(K, V)] {
+        self.entries.as_slice()
+    }
+
+    /// Convert this map into its underlying `Vec` of entries sorted by keys.
+    #[inline]
+    pub fn into_vec(self) -> Vec<(K, V)> {
+        self.entries
+    }
+
+    /// Return the entry with the smallest key.
+    #[inline]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.first().map(|(k, v)| (k, v))
+    }
+
+    /// Return the entry with the largest key.
+    #[inline]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.last().map(|(k, v)| (k, v))
+    }
+
+    /// Remove and return the entry with the smallest key.
+    #[inline]
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        if self.entries.is_empty() {
+            None
+        } else {
+            Some(self.entries.remove(0))
+        }
+    }
+
+    /// Remove and return the entry with the largest key.
+    #[inline]
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.entries.pop()
+    }
+
+    /// Keep only the entries specified by the predicate.
+    #[inline]
+    pub fn retain<F: FnMut(&K, &mut V) -> bool>(&mut self, mut f: F) {
+        self.entries = core::mem::take(&mut self.entries)
+            .into_iter()
+            .filter_map(|(k, mut v)| if f(&k, &mut v) { Some((k, v)) } else { None })
+            .collect();
+    }
+
+    /// Iterate over the entries in ascending order by keys.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.entries.iter())
+    }
+
+    /// Iterate over the entries in ascending order by keys, with mutable references to the values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut::new(self.entries.iter_mut())
+    }
+
+    /// Iterate over the keys in ascending order.
+    #[inline]
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys::new(self.entries.iter())
+    }
+
+    /// Iterate over the values in ascending order by keys.
+    #[inline]
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values::new(self.entries.iter())
+    }
+
+    /// Iterate over mutable references to the values in ascending order by keys.
+    #[inline]
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut::new(self.entries.iter_mut())
+    }
+}
+
+impl<K: Ord, V> SortedVecMap<K, V> {
+    /// Create a map from a `Vec` of entries which is already sorted in ascending order by keys and has no duplicate keys. Return the `Vec` back if it is not.
+    pub fn from_sorted_vec(entries: Vec<(K, V)>) -> Result<Self, Vec<(K, V)>> {
+        if entries.windows(2).all(|w| w[0].0 < w[1].0) {
+            Ok(SortedVecMap {
+                entries,
+            })
+        } else {
+            Err(entries)
+        }
+    }
+
+    #[inline]
+    fn search<Q: ?Sized + Ord>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>, {
+        self.entries.sorted_binary_search_by(|(k, _)| k.borrow().cmp(key))
+    }
+
+    /// Insert a key-value pair into this map. If the map already had this key, the value is replaced and the old value is returned. The key itself is not updated.
+    #[inline]
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Some(entry.insert(value)),
+            Entry::Vacant(entry) => {
+                entry.insert(value);
+
+                None
+            },
+        }
+    }
+
+    /// Get the entry of a key for in-place manipulation. Only one binary search is performed.
+    #[inline]
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        let index = self.entries.get_sorted_insert_index_binary_by(|(k, _)| k.cmp(&key));
+
+        // the binary search returns the index after the entry of the key, if there is one
+        if index > 0 && self.entries[index - 1].0 == key {
+            Entry::Occupied(OccupiedEntry::new(&mut self.entries, index - 1))
+        } else {
+            Entry::Vacant(VacantEntry::new(&mut self.entries, index, key))
+        }
+    }
+
+    /// Return `true` if this map contains a value for the key.
+    #[inline]
+    pub fn contains_key<Q: ?Sized + Ord>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>, {
+        self.search(key).is_ok()
+    }
+
+    /// Return the index of the key in the underlying sorted entries.
+    #[inline]
+    pub fn index_of<Q: ?Sized + Ord>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>, {
+        self.search(key).ok()
+    }
+
+    /// Return a reference to the value corresponding to the key.
+    #[inline]
+    pub fn get<Q: ?Sized + Ord>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>, {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    /// Return the key-value pair corresponding to the key.
+    #[inline]
+    pub fn get_key_value<Q: ?Sized + Ord>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>, {
+        match self.search(key) {
+            Ok(index) => {
+                let (k, v) = &self.entries[index];
+
+                Some((k, v))
+            },
+            Err(_) => None,
+        }
+    }
+
+    /// Return a mutable reference to the value corresponding to the key.
+    #[inline]
+    pub fn get_mut<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>, {
+        match self.search(key) {
+            Ok(index) => Some(&mut self.entries[index].1),
+            Err(_) => None,
+        }
+    }
+
+    /// Remove a key from this map and return its value if the key was previously in the map.
+    #[inline]
+    pub fn remove<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>, {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// Remove a key from this map and return the stored key and value if the key was previously in the map.
+    #[inline]
+    pub fn remove_entry<Q: ?Sized + Ord>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>, {
+        match self.search(key) {
+            Ok(index) => Some(self.entries.remove(index)),
+            Err(_) => None,
+        }
+    }
+
+    /// Iterate over the entries whose keys are within the range, in ascending order by keys.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if the start of the range is greater than the end, or if both bounds are excluded and equal.
+    pub fn range<Q: ?Sized + Ord, R: RangeBounds<Q>>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>, {
+        match (range.start_bound(), range.end_bound()) {
+            (Bound::Excluded(start), Bound::Excluded(end)) if start == end => {
+                panic!("range start and end are equal and excluded")
+            },
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) if start > end => panic!("range start is greater than range end"),
+            _ => (),
+        }
+
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.entries.partition_point(|(k, _)| k.borrow() < start),
+            Bound::Excluded(start) => self.entries.partition_point(|(k, _)| k.borrow() <= start),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.entries.partition_point(|(k, _)| k.borrow() <= end),
+            Bound::Excluded(end) => self.entries.partition_point(|(k, _)| k.borrow() < end),
+            Bound::Unbounded => self.entries.len(),
+        };
+
+        Iter::new(self.entries[start..end].iter())
+    }
+}
+
+impl<K, V> Default for SortedVecMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        SortedVecMap::new()
+    }
+}
+
+impl<K: Debug, V: Debug> Debug for SortedVecMap<K, V> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Borrow<Q> + Ord, Q: ?Sized + Ord, V> Index<&Q> for SortedVecMap<K, V> {
+    type Output = V;
+
+    /// Return a reference to the value corresponding to the key.
+    ///
+    /// ## Panics
+    ///
+    /// This function will panic if the key is not present in the map.
+    #[inline]
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for SortedVecMap<K, V> {
+    /// Create a map from key-value pairs. If a key appears more than once, the value of the last pair is kept, the same as inserting the pairs one by one.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = SortedVecMap::new();
+
+        map.extend(iter);
+
+        map
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for SortedVecMap<K, V> {
+    /// Insert key-value pairs into this map in bulk. If a key is already in the map or appears more than once, the value of the last pair is kept, the same as inserting the pairs one by one.
+    ///
+    /// Only the new pairs are sorted, and then they are merged into the existing entries, so an extension with `m` pairs costs `O(n + m log m)`, or `O(m log m)` if all of their keys are greater than the existing ones.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        let len = self.entries.len();
+
+        self.entries.extend(iter);
+
+        if self.entries.len() == len {
+            return;
+        }
+
+        let mut tail = self.entries.split_off(len);
+
+        // the stable sort keeps the entries which are inserted later after the earlier ones
+        tail.sort_by(|a, b| a.0.cmp(&b.0));
+
+        tail.dedup_by(|later, earlier| {
+            if later.0 == earlier.0 {
+                core::mem::swap(&mut later.1, &mut earlier.1);
+
+                true
+            } else {
+                false
+            }
+        });
+
+        let appendable = match self.entries.last() {
+            Some(last) => last.0 < tail[0].0,
+            None => true,
+        };
+
+        if appendable {
+            self.entries.append(&mut tail);
+
+            return;
+        }
+
+        // merge the sorted tail into the existing entries, keeping the existing key and the new value on equal keys
+        let mut merged = Vec::with_capacity(self.entries.len() + tail.len());
+        let mut existing = core::mem::take(&mut self.entries).into_iter().peekable();
+        let mut tail = tail.into_iter().peekable();
+
+        loop {
+            let ordering = match (existing.peek(), tail.peek()) {
+                (Some(a), Some(b)) => a.0.cmp(&b.0),
+                (Some(_), None) => {
+                    merged.extend(existing);
+
+                    break;
+                },
+                (None, _) => {
+                    merged.extend(tail);
+
+                    break;
+                },
+            };
+
+            match ordering {
+                Ordering::Less => merged.push(existing.next().unwrap()),
+                Ordering::Greater => merged.push(tail.next().unwrap()),
+                Ordering::Equal => {
+                    let (key, _) = existing.next().unwrap();
+                    let (_, value) = tail.next().unwrap();
+
+                    merged.push((key, value));
+                },
+            }
+        }
+
+        self.entries = merged;
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a SortedVecMap<K, V> {
+    type IntoIter = Iter<'a, K, V>;
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut SortedVecMap<K, V> {
+    type IntoIter = IterMut<'a, K, V>;
+    type Item = (&'a K, &'a mut V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, V> IntoIterator for SortedVecMap<K, V> {
+    type IntoIter = alloc::vec::IntoIter<(K, V)>;
+    type Item = (K, V);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<K, V> From<SortedVecMap<K, V>> for Vec<(K, V)> {
+    #[inline]
+    fn from(map: SortedVecMap<K, V>) -> Self {
+        map.entries
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Formatter},
+    marker::PhantomData,
+};
+
+use ::serde::{
+    de::{Error, MapAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::SortedVecMap;
+use crate::serde::DeserializeResorted;
+
+impl<K: Serialize, V: Serialize> Serialize for SortedVecMap<K, V> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+struct EntriesVisitor<K, V>(PhantomData<(K, V)>);
+
+impl<'de, K: Deserialize<'de>, V: Deserialize<'de>> Visitor<'de> for EntriesVisitor<K, V> {
+    type Value = Vec<(K, V)>;
+
+    #[inline]
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a map")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        // do not trust the size hint too much
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(4096));
+
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(entries)
+    }
+}
+
+impl<'de, K: Ord + Deserialize<'de>, V: Deserialize<'de>> Deserialize<'de> for SortedVecMap<K, V> {
+    /// Deserialize a map whose keys are in ascending order without duplicates. Return an error if they are not.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = deserializer.deserialize_map(EntriesVisitor(PhantomData))?;
+
+        if let Some(index) = entries.windows(2).position(|w| w[0].0 >= w[1].0) {
+            return Err(D::Error::custom(format_args!(
+                "the key at index {} is not less than the key at index {}",
+                index,
+                index + 1
+            )));
+        }
+
+        Ok(SortedVecMap {
+            entries,
+        })
+    }
+}
+
+impl<'de, K: Ord + Deserialize<'de>, V: Deserialize<'de>> DeserializeResorted<'de>
+    for SortedVecMap<K, V>
+{
+    /// Deserialize a map by sorting its entries. If a key appears more than once, the value of the last entry is kept.
+    #[inline]
+    fn deserialize_resorted<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = deserializer.deserialize_map(EntriesVisitor(PhantomData))?;
+
+        Ok(entries.into_iter().collect())
+    }
+}
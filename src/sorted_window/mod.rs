@@ -0,0 +1,223 @@
+//! A sorted buffer which only keeps the elements within a window of keys, such as timestamps, behind the latest one.
+
+mod window_key;
+
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use std::collections::{vec_deque, VecDeque};
+
+pub use window_key::*;
+
+use crate::SortedInsertGallopByKey;
+
+/// The result of inserting an element to a `SortedWindow`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WindowInsert<T> {
+    /// The element has been inserted at `index`, after the elements which fell out of the window have been evicted. `evicted` contains them in ascending order.
+    Inserted { index: usize, evicted: Vec<T> },
+    /// The element is older than the window, so it has been rejected and is given back.
+    TooLate(T),
+}
+
+impl<T> WindowInsert<T> {
+    /// Return `true` if the element has been inserted.
+    #[inline]
+    pub fn is_inserted(&self) -> bool {
+        matches!(self, WindowInsert::Inserted { .. })
+    }
+}
+
+/// A buffer of elements sorted in ascending order by a key, such as a timestamp, which only keeps the elements whose keys are within `span` of the latest key.
+///
+/// The latest key is the newest key which has been inserted or passed to `advance`, so it never moves back, even when `advance` evicts every element. Elements are inserted with galloping search from the back, so elements arriving in order or slightly out of order cost `O(1)` to `O(log d)` comparisons, where `d` is how far from the back they land. Inserting an element with a newer key evicts the elements which fall out of the window from the front. An element older than the window is rejected.
+///
+/// ```rust
+/// use sorted_insert::{SortedWindow, WindowInsert};
+///
+/// let mut window = SortedWindow::new(10, |sample: &(u64, f64)| &sample.0);
+///
+/// window.insert((100, 0.5));
+/// window.insert((95, 0.2));
+///
+/// assert_eq!(
+///     WindowInsert::Inserted {
+///         index: 1, evicted: vec![(95, 0.2)]
+///     },
+///     window.insert((108, 0.7))
+/// );
+/// assert_eq!(WindowInsert::TooLate((97, 0.1)), window.insert((97, 0.1)));
+/// assert_eq!(2, window.len());
+/// ```
+pub struct SortedWindow<T, K: WindowKey, F: Fn(&T) -> &K> {
+    elements: VecDeque<T>,
+    span:     K::Span,
+    key:      F,
+    /// The newest key which has been inserted or advanced to.
+    latest:   Option<K>,
+}
+
+impl<T, K: WindowKey, F: Fn(&T) -> &K> SortedWindow<T, K, F> {
+    /// Create an empty window which keeps the elements whose keys, extracted by `key`, are not older than `span` behind the latest key.
+    #[inline]
+    pub fn new(span: K::Span, key: F) -> Self {
+        SortedWindow {
+            elements: VecDeque::new(),
+            span,
+            key,
+            latest: None,
+        }
+    }
+
+    /// Return the span of the window.
+    #[inline]
+    pub fn span(&self) -> &K::Span {
+        &self.span
+    }
+
+    /// Return the number of elements in this window.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Return `true` if this window contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// Remove all elements. The latest key is kept, so elements older than the window are still rejected.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.elements.clear();
+    }
+
+    /// Return the element at `index`.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.elements.get(index)
+    }
+
+    /// Return the oldest element.
+    #[inline]
+    pub fn oldest(&self) -> Option<&T> {
+        self.elements.front()
+    }
+
+    /// Return the newest element.
+    #[inline]
+    pub fn newest(&self) -> Option<&T> {
+        self.elements.back()
+    }
+
+    /// Return the newest key which has been inserted or passed to `advance`, or `None` if there has been none.
+    #[inline]
+    pub fn latest(&self) -> Option<&K> {
+        self.latest.as_ref()
+    }
+
+    /// Return the oldest key which is still within the window, or `None` if there is no latest key yet or the window reaches back before the smallest possible key.
+    #[inline]
+    pub fn start(&self) -> Option<K> {
+        self.latest.as_ref().and_then(|latest| latest.checked_sub(&self.span))
+    }
+
+    /// Iterate over the elements in ascending order of their keys.
+    #[inline]
+    pub fn iter(&self) -> vec_deque::Iter<'_, T> {
+        self.elements.iter()
+    }
+
+    /// Extract the underlying `VecDeque`.
+    #[inline]
+    pub fn as_vec_deque(&self) -> &VecDeque<T> {
+        &self.elements
+    }
+
+    /// Convert this window into its underlying `VecDeque`.
+    #[inline]
+    pub fn into_vec_deque(self) -> VecDeque<T> {
+        self.elements
+    }
+
+    /// Insert an element and evict the elements which fall out of the window. If the element itself is older than the window, it is rejected with `WindowInsert::TooLate`.
+    ///
+    /// Elements with equal keys are kept in their insertion order.
+    pub fn insert(&mut self, element: T) -> WindowInsert<T> {
+        let key = &self.key;
+
+        if let Some(start) = self.start() {
+            if *key(&element) < start {
+                return WindowInsert::TooLate(element);
+            }
+        }
+
+        let is_latest = match self.latest.as_ref() {
+            Some(latest) => key(&element) > latest,
+            None => true,
+        };
+
+        if is_latest {
+            self.latest = Some(key(&element).clone());
+        }
+
+        let index = self.elements.sorted_insert_gallop_asc_by_key(element, key);
+
+        let evicted = match self.start() {
+            Some(start) => self.evict_before(&start),
+            None => Vec::new(),
+        };
+
+        WindowInsert::Inserted {
+            index: index - evicted.len(),
+            evicted,
+        }
+    }
+
+    /// Move the window forward so that its latest key is at least `now`, and return the evicted elements in ascending order. Later insertions of elements older than the moved window are rejected. It does nothing if `now` is not newer than the latest key.
+    pub fn advance(&mut self, now: &K) -> Vec<T> {
+        if let Some(latest) = self.latest.as_ref() {
+            if latest >= now {
+                return Vec::new();
+            }
+        }
+
+        self.latest = Some(now.clone());
+
+        match self.start() {
+            Some(start) => self.evict_before(&start),
+            None => Vec::new(),
+        }
+    }
+
+    fn evict_before(&mut self, start: &K) -> Vec<T> {
+        let key = &self.key;
+        let count = self.elements.partition_point(|e| key(e) < start);
+
+        self.elements.drain(..count).collect()
+    }
+}
+
+impl<T: Debug, K: WindowKey + Debug, F: Fn(&T) -> &K> Debug for SortedWindow<T, K, F>
+where
+    K::Span: Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SortedWindow")
+            .field("elements", &self.elements)
+            .field("span", &self.span)
+            .field("latest", &self.latest)
+            .finish()
+    }
+}
+
+impl<'a, T, K: WindowKey, F: Fn(&T) -> &K> IntoIterator for &'a SortedWindow<T, K, F> {
+    type IntoIter = vec_deque::Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements.iter()
+    }
+}
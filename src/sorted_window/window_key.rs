@@ -0,0 +1,54 @@
+use std::time::{Duration, Instant, SystemTime};
+
+/// Keys which can be used to bound a `SortedWindow`, such as timestamps.
+pub trait WindowKey: Ord + Clone {
+    /// The type of the span of a window, in the units of the key.
+    type Span;
+
+    /// Return the key which is `span` before this key, or `None` if it would be before the smallest possible key.
+    fn checked_sub(&self, span: &Self::Span) -> Option<Self>;
+}
+
+macro_rules! impl_window_key_for_integers {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl WindowKey for $t {
+                type Span = $t;
+
+                #[inline]
+                fn checked_sub(&self, span: &$t) -> Option<$t> {
+                    <$t>::checked_sub(*self, *span)
+                }
+            }
+        )*
+    };
+}
+
+impl_window_key_for_integers!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl WindowKey for Duration {
+    type Span = Duration;
+
+    #[inline]
+    fn checked_sub(&self, span: &Duration) -> Option<Duration> {
+        Duration::checked_sub(*self, *span)
+    }
+}
+
+impl WindowKey for Instant {
+    type Span = Duration;
+
+    #[inline]
+    fn checked_sub(&self, span: &Duration) -> Option<Instant> {
+        Instant::checked_sub(self, *span)
+    }
+}
+
+impl WindowKey for SystemTime {
+    type Span = Duration;
+
+    #[inline]
+    fn checked_sub(&self, span: &Duration) -> Option<SystemTime> {
+        SystemTime::checked_sub(self, *span)
+    }
+}
@@ -0,0 +1,255 @@
+//! A scheduler of jobs ordered by their deadlines, which keeps jobs with equal deadlines in FIFO order.
+
+use alloc::collections::BTreeMap;
+use core::{cmp::Ordering, iter::FusedIterator};
+use std::collections::{vec_deque, VecDeque};
+
+use crate::SortedInsertGallopByKey;
+
+/// A handle to a job scheduled in a `StableScheduler`. It stays valid until the job is popped or cancelled, no matter how many other jobs are scheduled. A handle must only be used with the scheduler which returned it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct ScheduleHandle(u64);
+
+#[derive(Debug, Clone)]
+struct Entry<K, T> {
+    deadline: K,
+    /// The order of scheduling, which breaks ties between equal deadlines.
+    seq:      u64,
+    id:       u64,
+    job:      T,
+}
+
+/// A scheduler of jobs ordered by their deadlines, backed by a sorted `VecDeque`. Jobs with equal deadlines are popped in the order they are scheduled, unlike with a `BinaryHeap`.
+///
+/// Jobs are inserted with galloping search from the back, so scheduling jobs in order of their deadlines is cheap. Handles are located by binary search, so cancelling or rescheduling a job costs `O(log n)` comparisons plus moving the elements of the `VecDeque`.
+///
+/// ```rust
+/// use sorted_insert::StableScheduler;
+///
+/// let mut scheduler = StableScheduler::new();
+///
+/// scheduler.schedule(20, "b");
+/// scheduler.schedule(10, "a");
+/// let c = scheduler.schedule(20, "c");
+/// scheduler.schedule(20, "d");
+///
+/// scheduler.reschedule(c, 30);
+///
+/// assert_eq!(Some((10, "a")), scheduler.pop_due(&25));
+/// assert_eq!(Some((20, "b")), scheduler.pop_due(&25));
+/// assert_eq!(Some((20, "d")), scheduler.pop_due(&25));
+/// assert_eq!(None, scheduler.pop_due(&25));
+///
+/// assert_eq!(Some((30, "c")), scheduler.cancel(c));
+/// assert!(scheduler.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct StableScheduler<K, T> {
+    entries:   VecDeque<Entry<K, T>>,
+    /// The deadline and the sequence number of each scheduled job, by its id.
+    positions: BTreeMap<u64, (K, u64)>,
+    next_seq:  u64,
+}
+
+impl<K, T> StableScheduler<K, T> {
+    /// Create an empty scheduler.
+    #[inline]
+    pub fn new() -> Self {
+        StableScheduler {
+            entries: VecDeque::new(), positions: BTreeMap::new(), next_seq: 0
+        }
+    }
+
+    /// Return the number of scheduled jobs.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Return `true` if no jobs are scheduled.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove all jobs. The handles of the removed jobs are no longer valid.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.positions.clear();
+    }
+
+    /// Return the next job to be popped and its deadline.
+    #[inline]
+    pub fn peek(&self) -> Option<(&K, &T)> {
+        self.entries.front().map(|entry| (&entry.deadline, &entry.job))
+    }
+
+    /// Iterate over the scheduled jobs and their deadlines in the order they will be popped.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, T> {
+        Iter {
+            iter: self.entries.iter()
+        }
+    }
+
+    #[inline]
+    fn next_seq(&mut self) -> u64 {
+        let seq = self.next_seq;
+
+        self.next_seq += 1;
+
+        seq
+    }
+}
+
+impl<K: Ord + Clone, T> StableScheduler<K, T> {
+    /// Schedule a job at a deadline and return its handle. The job is popped after the jobs which are already scheduled at the same deadline.
+    pub fn schedule(&mut self, deadline: K, job: T) -> ScheduleHandle {
+        let seq = self.next_seq();
+
+        self.insert(Entry {
+            deadline,
+            seq,
+            id: seq,
+            job,
+        });
+
+        ScheduleHandle(seq)
+    }
+
+    /// Pop the next job if its deadline is not after `now`, and return it with its deadline.
+    pub fn pop_due(&mut self, now: &K) -> Option<(K, T)> {
+        if self.entries.front()?.deadline > *now {
+            return None;
+        }
+
+        let entry = self.entries.pop_front().unwrap();
+
+        self.positions.remove(&entry.id);
+
+        Some((entry.deadline, entry.job))
+    }
+
+    /// Cancel a job and return it with its deadline. Return `None` if the job has already been popped or cancelled.
+    pub fn cancel(&mut self, handle: ScheduleHandle) -> Option<(K, T)> {
+        let index = self.locate(handle)?;
+        let entry = self.entries.remove(index).unwrap();
+
+        self.positions.remove(&entry.id);
+
+        Some((entry.deadline, entry.job))
+    }
+
+    /// Move a job to a new deadline and return its old deadline. The job is popped after the jobs which are already scheduled at the new deadline, even if the deadline does not change. Return `None` if the job has already been popped or cancelled.
+    pub fn reschedule(&mut self, handle: ScheduleHandle, deadline: K) -> Option<K> {
+        let index = self.locate(handle)?;
+        let mut entry = self.entries.remove(index).unwrap();
+
+        let old_deadline = core::mem::replace(&mut entry.deadline, deadline);
+
+        entry.seq = self.next_seq();
+
+        self.insert(entry);
+
+        Some(old_deadline)
+    }
+
+    /// Return `true` if the job is still scheduled.
+    #[inline]
+    pub fn contains(&self, handle: ScheduleHandle) -> bool {
+        self.positions.contains_key(&handle.0)
+    }
+
+    /// Return the deadline of a job, or `None` if it has already been popped or cancelled.
+    #[inline]
+    pub fn deadline(&self, handle: ScheduleHandle) -> Option<&K> {
+        self.positions.get(&handle.0).map(|(deadline, _)| deadline)
+    }
+
+    /// Return a reference to a job, or `None` if it has already been popped or cancelled.
+    #[inline]
+    pub fn get(&self, handle: ScheduleHandle) -> Option<&T> {
+        let index = self.locate(handle)?;
+
+        Some(&self.entries[index].job)
+    }
+
+    /// Return a mutable reference to a job, or `None` if it has already been popped or cancelled.
+    #[inline]
+    pub fn get_mut(&mut self, handle: ScheduleHandle) -> Option<&mut T> {
+        let index = self.locate(handle)?;
+
+        Some(&mut self.entries[index].job)
+    }
+
+    fn insert(&mut self, entry: Entry<K, T>) {
+        self.positions.insert(entry.id, (entry.deadline.clone(), entry.seq));
+
+        // the new entry has the largest sequence number, so it goes after the entries with equal deadlines
+        self.entries.sorted_insert_gallop_asc_by_key(entry, |entry| &entry.deadline);
+    }
+
+    /// Find the index of a job. The entries are sorted by their deadlines and then their sequence numbers, so the pair identifies one entry.
+    fn locate(&self, handle: ScheduleHandle) -> Option<usize> {
+        let (deadline, seq) = self.positions.get(&handle.0)?;
+
+        let index = self
+            .entries
+            .binary_search_by(|entry| match entry.deadline.cmp(deadline) {
+                Ordering::Equal => entry.seq.cmp(seq),
+                ordering => ordering,
+            })
+            .ok()?;
+
+        Some(index)
+    }
+}
+
+impl<K, T> Default for StableScheduler<K, T> {
+    #[inline]
+    fn default() -> Self {
+        StableScheduler::new()
+    }
+}
+
+impl<'a, K, T> IntoIterator for &'a StableScheduler<K, T> {
+    type IntoIter = Iter<'a, K, T>;
+    type Item = (&'a K, &'a T);
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the jobs of a `StableScheduler` and their deadlines, in the order they will be popped.
+#[derive(Debug, Clone)]
+pub struct Iter<'a, K, T> {
+    iter: vec_deque::Iter<'a, Entry<K, T>>,
+}
+
+impl<'a, K, T> Iterator for Iter<'a, K, T> {
+    type Item = (&'a K, &'a T);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|entry| (&entry.deadline, &entry.job))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, T> DoubleEndedIterator for Iter<'a, K, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|entry| (&entry.deadline, &entry.job))
+    }
+}
+
+impl<'a, K, T> ExactSizeIterator for Iter<'a, K, T> {}
+
+impl<'a, K, T> FusedIterator for Iter<'a, K, T> {}
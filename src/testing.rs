@@ -0,0 +1,510 @@
+//! A reference model for testing implementations of the insertion traits. This module is available when the `testing` feature is enabled.
+//!
+//! The checker keeps a plain `Vec` as the model, sorted by a `Comparator`, and applies every operation to both the model and the collection under test. Each operation inserts an element with one of several insertion strategies, each of which calls the trait method under test. After each operation, the checker checks the returned index and the contents of the collection, and reports the first divergence.
+//!
+//! The built-in strategies only require the trait they call, so a collection which only implements `SortedInsertBy` can be checked with `Linear`. `InsertWith` wraps a closure, for the traits which have no built-in strategy, such as the `*ByKey` traits or the `Arc<Mutex<T>>` / `Arc<RwLock<T>>` collections.
+//!
+//! ```rust
+//! use sorted_insert::{
+//!     testing::{
+//!         check_operations, check_random, Binary, Gallop, Linear, Operation,
+//!     },
+//!     Order,
+//! };
+//!
+//! let mut v: Vec<u32> = Vec::new();
+//!
+//! check_operations(
+//!     &mut v,
+//!     Order::Ascending,
+//!     &mut [&mut Linear, &mut Binary],
+//!     [Operation::new(0, 3), Operation::new(1, 1), Operation::new(0, 3)],
+//! )
+//! .unwrap();
+//!
+//! assert_eq!([1, 3, 3], v.as_slice());
+//!
+//! check_random(
+//!     &mut v,
+//!     Order::Ascending,
+//!     &mut [&mut Linear, &mut Gallop],
+//!     1000,
+//!     7,
+//!     |rng| rng.next_below(16) as u32,
+//! )
+//! .unwrap();
+//! ```
+
+use alloc::vec::Vec;
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug, Display, Formatter},
+};
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::{
+    Comparator, SortedInsertBasic, SortedInsertBinaryBy, SortedInsertBy, SortedInsertComparator,
+    SortedInsertGallopBy, SortedInsertWithHintBy,
+};
+
+/// Where an insertion strategy may put an element among the elements which compare equal to it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Placement {
+    /// After the equal elements, like linear search and galloping search.
+    Last,
+    /// Anywhere among the equal elements, like binary search.
+    Any,
+}
+
+/// A way of inserting an element into the collection under test, which calls the trait method under test. `K` is the comparator by which the collection is sorted.
+pub trait InsertStrategy<C: ?Sized, T, K> {
+    /// Return where this strategy may put an element among the equal elements.
+    fn placement(&self) -> Placement;
+
+    /// Insert an element into `collection`, which is sorted by `comparator`, and return the inserted index.
+    fn insert(&mut self, collection: &mut C, element: T, comparator: &K) -> usize;
+}
+
+/// Insert elements with `SortedInsertBy::sorted_insert_by`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Linear;
+
+impl<C: SortedInsertBy<T> + ?Sized, T, K: Comparator<T>> InsertStrategy<C, T, K> for Linear {
+    #[inline]
+    fn placement(&self) -> Placement {
+        Placement::Last
+    }
+
+    #[inline]
+    fn insert(&mut self, collection: &mut C, element: T, comparator: &K) -> usize {
+        collection.sorted_insert_by(element, |e, element| {
+            comparator.compare(e, element) != Ordering::Greater
+        })
+    }
+}
+
+/// Insert elements with `SortedInsertBinaryBy::sorted_insert_binary_by`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Binary;
+
+impl<C: SortedInsertBinaryBy<T> + ?Sized, T, K: Comparator<T>> InsertStrategy<C, T, K> for Binary {
+    #[inline]
+    fn placement(&self) -> Placement {
+        Placement::Any
+    }
+
+    #[inline]
+    fn insert(&mut self, collection: &mut C, element: T, comparator: &K) -> usize {
+        collection.sorted_insert_binary_by(element, |e, element| comparator.compare(e, element))
+    }
+}
+
+/// Insert elements with `SortedInsertGallopBy::sorted_insert_gallop_by`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Gallop;
+
+impl<C: SortedInsertGallopBy<T> + ?Sized, T, K: Comparator<T>> InsertStrategy<C, T, K> for Gallop {
+    #[inline]
+    fn placement(&self) -> Placement {
+        Placement::Last
+    }
+
+    #[inline]
+    fn insert(&mut self, collection: &mut C, element: T, comparator: &K) -> usize {
+        collection.sorted_insert_gallop_by(element, |e, element| comparator.compare(e, element))
+    }
+}
+
+/// Insert elements with `SortedInsertWithHintBy::sorted_insert_with_hint_by`, using the index returned by the previous insertion as the hint.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct WithHint {
+    /// The hint of the next insertion.
+    pub hint: usize,
+}
+
+impl<C: SortedInsertWithHintBy<T> + ?Sized, T, K: Comparator<T>> InsertStrategy<C, T, K>
+    for WithHint
+{
+    #[inline]
+    fn placement(&self) -> Placement {
+        Placement::Last
+    }
+
+    #[inline]
+    fn insert(&mut self, collection: &mut C, element: T, comparator: &K) -> usize {
+        let index = collection.sorted_insert_with_hint_by(element, self.hint, |e, element| {
+            comparator.compare(e, element)
+        });
+
+        self.hint = index;
+
+        index
+    }
+}
+
+/// Insert elements with `SortedInsertComparator::sorted_insert_binary_by_comparator`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BinaryByComparator;
+
+impl<C: SortedInsertComparator<T> + ?Sized, T, K: Comparator<T>> InsertStrategy<C, T, K>
+    for BinaryByComparator
+{
+    #[inline]
+    fn placement(&self) -> Placement {
+        Placement::Any
+    }
+
+    #[inline]
+    fn insert(&mut self, collection: &mut C, element: T, comparator: &K) -> usize {
+        collection.sorted_insert_binary_by_comparator(element, comparator)
+    }
+}
+
+/// Insert elements with a closure, which must insert them in the order of the comparator of the checker.
+#[derive(Debug, Copy, Clone)]
+pub struct InsertWith<F> {
+    placement: Placement,
+    f:         F,
+}
+
+impl<F> InsertWith<F> {
+    /// Create a strategy which inserts elements with `f` and may put them at `placement` among the equal elements.
+    #[inline]
+    pub const fn new(placement: Placement, f: F) -> Self {
+        InsertWith {
+            placement,
+            f,
+        }
+    }
+}
+
+impl<C: ?Sized, T, K, F: FnMut(&mut C, T) -> usize> InsertStrategy<C, T, K> for InsertWith<F> {
+    #[inline]
+    fn placement(&self) -> Placement {
+        self.placement
+    }
+
+    #[inline]
+    fn insert(&mut self, collection: &mut C, element: T, _comparator: &K) -> usize {
+        (self.f)(collection, element)
+    }
+}
+
+/// An operation applied to both the collection under test and the model, which inserts `element` with the strategy at index `strategy` of the strategies given to the checker.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Operation<T> {
+    pub strategy: usize,
+    pub element:  T,
+}
+
+impl<T> Operation<T> {
+    /// Create an operation which inserts `element` with the strategy at index `strategy`.
+    #[inline]
+    pub const fn new(strategy: usize, element: T) -> Self {
+        Operation {
+            strategy,
+            element,
+        }
+    }
+}
+
+/// The first difference between the collection under test and the model.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Divergence<T> {
+    /// The index returned by an insertion is not one where the strategy can insert the element.
+    Index {
+        /// The number of operations applied before this one.
+        step:      usize,
+        operation: Operation<T>,
+        /// The index returned by the collection.
+        index:     usize,
+        /// The first index where the element can be inserted.
+        start:     usize,
+        /// The last index where the element can be inserted.
+        end:       usize,
+    },
+    /// The contents of the collection differ from the model after an operation.
+    Content {
+        /// The number of operations applied before this one.
+        step:      usize,
+        operation: Operation<T>,
+        /// The first index where the contents differ.
+        index:     usize,
+        /// The element of the model at `index`, or `None` if the model is shorter.
+        expected:  Option<T>,
+        /// The element of the collection at `index`, or `None` if the collection is shorter.
+        found:     Option<T>,
+    },
+}
+
+impl<T> Divergence<T> {
+    /// Return the number of operations applied before the diverging one.
+    #[inline]
+    pub fn step(&self) -> usize {
+        match self {
+            Divergence::Index {
+                step, ..
+            }
+            | Divergence::Content {
+                step, ..
+            } => *step,
+        }
+    }
+
+    /// Return the diverging operation.
+    #[inline]
+    pub const fn operation(&self) -> &Operation<T> {
+        match self {
+            Divergence::Index {
+                operation, ..
+            }
+            | Divergence::Content {
+                operation, ..
+            } => operation,
+        }
+    }
+}
+
+impl<T: Debug> Display for Divergence<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Divergence::Index {
+                step,
+                operation,
+                index,
+                start,
+                end,
+            } => write!(
+                f,
+                "step {} (inserting {:?} with strategy {}) returned the index {}, but the element \
+                 can only be inserted at {}..={}",
+                step, operation.element, operation.strategy, index, start, end
+            ),
+            Divergence::Content {
+                step,
+                operation,
+                index,
+                expected,
+                found,
+            } => write!(
+                f,
+                "after step {} (inserting {:?} with strategy {}), the element at index {} is \
+                 {:?}, but {:?} is expected",
+                step, operation.element, operation.strategy, index, found, expected
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Debug> Error for Divergence<T> {}
+
+/// A checker which compares a collection under test with a reference model, one operation at a time.
+#[derive(Debug, Clone)]
+pub struct ReferenceChecker<T, K> {
+    comparator: K,
+    model:      Vec<T>,
+    step:       usize,
+}
+
+impl<T: Clone, K: Comparator<T>> ReferenceChecker<T, K> {
+    /// Create a checker whose model starts with the contents of `collection`, which must be sorted by `comparator`.
+    pub fn new<C: SortedInsertBasic<T> + ?Sized>(collection: &C, comparator: K) -> Self {
+        let mut model = Vec::new();
+
+        while let Some(element) = collection.get_element(model.len()) {
+            model.push(element.clone());
+        }
+
+        ReferenceChecker {
+            comparator,
+            model,
+            step: 0,
+        }
+    }
+
+    /// Return the comparator by which the collection is sorted.
+    #[inline]
+    pub fn comparator(&self) -> &K {
+        &self.comparator
+    }
+
+    /// Return the number of operations applied so far.
+    #[inline]
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    /// Return the contents of the model.
+    #[inline]
+    pub fn model(&self) -> &[T] {
+        &self.model
+    }
+
+    /// Apply an operation to both `collection` and the model, then check the returned index and the contents of `collection`. The elements are compared with the comparator, so elements which compare equal are not told apart.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the strategy of the operation is out of the bounds of `strategies`.
+    pub fn apply<C: SortedInsertBasic<T> + ?Sized>(
+        &mut self,
+        collection: &mut C,
+        strategies: &mut [&mut dyn InsertStrategy<C, T, K>],
+        operation: Operation<T>,
+    ) -> Result<(), Divergence<T>> {
+        let step = self.step;
+
+        self.step += 1;
+
+        let strategy = &mut strategies[operation.strategy];
+        let element = operation.element.clone();
+
+        let comparator = &self.comparator;
+
+        let start =
+            self.model.partition_point(|e| comparator.compare(e, &element) == Ordering::Less);
+        let end =
+            self.model.partition_point(|e| comparator.compare(e, &element) != Ordering::Greater);
+
+        let index = strategy.insert(collection, element.clone(), comparator);
+
+        let valid = match strategy.placement() {
+            Placement::Last => index == end,
+            Placement::Any => start <= index && index <= end,
+        };
+
+        if !valid {
+            return Err(Divergence::Index {
+                step,
+                operation,
+                index,
+                start,
+                end,
+            });
+        }
+
+        self.model.insert(index, element);
+
+        let mut i = 0;
+
+        loop {
+            let expected = self.model.get(i);
+            let found = collection.get_element(i);
+
+            let same = match (expected, found) {
+                (Some(a), Some(b)) => self.comparator.compare(a, b) == Ordering::Equal,
+                (None, None) => return Ok(()),
+                _ => false,
+            };
+
+            if !same {
+                return Err(Divergence::Content {
+                    step,
+                    operation,
+                    index: i,
+                    expected: expected.cloned(),
+                    found: found.cloned(),
+                });
+            }
+
+            i += 1;
+        }
+    }
+}
+
+/// Apply a scripted sequence of operations to `collection`, which must be sorted by `comparator`, and return the first divergence from the reference model. Each operation picks one of `strategies` by its index.
+pub fn check_operations<
+    T: Clone,
+    K: Comparator<T>,
+    C: SortedInsertBasic<T> + ?Sized,
+    I: IntoIterator<Item = Operation<T>>,
+>(
+    collection: &mut C,
+    comparator: K,
+    strategies: &mut [&mut dyn InsertStrategy<C, T, K>],
+    operations: I,
+) -> Result<(), Divergence<T>> {
+    let mut checker = ReferenceChecker::new(collection, comparator);
+
+    for operation in operations {
+        checker.apply(collection, strategies, operation)?;
+    }
+
+    Ok(())
+}
+
+/// Apply `len` random operations to `collection`, which must be sorted by `comparator`, and return the first divergence from the reference model. Each operation picks one of `strategies` at random, and the elements are generated by `element`. The same `seed` reproduces the same sequence.
+///
+/// ## Panics
+///
+/// Panics if `strategies` is empty.
+pub fn check_random<
+    T: Clone,
+    K: Comparator<T>,
+    C: SortedInsertBasic<T> + ?Sized,
+    F: FnMut(&mut XorShift64) -> T,
+>(
+    collection: &mut C,
+    comparator: K,
+    strategies: &mut [&mut dyn InsertStrategy<C, T, K>],
+    len: usize,
+    seed: u64,
+    mut element: F,
+) -> Result<(), Divergence<T>> {
+    assert!(!strategies.is_empty(), "there must be at least one strategy");
+
+    let mut rng = XorShift64::new(seed);
+    let mut checker = ReferenceChecker::new(collection, comparator);
+
+    for _ in 0..len {
+        let strategy = rng.next_below(strategies.len() as u64) as usize;
+
+        checker.apply(collection, strategies, Operation::new(strategy, element(&mut rng)))?;
+    }
+
+    Ok(())
+}
+
+/// A small and fast xorshift pseudorandom number generator, for generating reproducible operation sequences. It is not suitable for cryptography.
+#[derive(Debug, Clone)]
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// Create a generator from a seed. A zero seed is replaced with a fixed non-zero one, because xorshift cannot leave the zero state.
+    #[inline]
+    pub const fn new(seed: u64) -> Self {
+        XorShift64 {
+            state: if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed }
+        }
+    }
+
+    /// Generate the next number.
+    #[inline]
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+
+        self.state = x;
+
+        x
+    }
+
+    /// Generate a number less than `bound`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `bound` is zero.
+    #[inline]
+    pub fn next_below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "the bound must not be zero");
+
+        self.next_u64() % bound
+    }
+}
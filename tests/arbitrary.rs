@@ -0,0 +1,32 @@
+#![cfg(feature = "arbitrary")]
+
+mod common;
+
+use ::arbitrary::{Arbitrary, Unstructured};
+use common::random_bytes;
+use sorted_insert::*;
+
+#[test]
+fn arbitrary_collections_are_sorted() {
+    for seed in 1..50 {
+        let bytes = random_bytes(1000, seed);
+        let mut u = Unstructured::new(&bytes);
+
+        let map = SortedVecMap::<u8, u8>::arbitrary(&mut u).unwrap();
+        let bag = SortedBag::<u8>::arbitrary(&mut u).unwrap();
+        let chunked = SortedChunkedVec::<u8>::arbitrary_take_rest(u).unwrap();
+
+        assert!(map.keys().zip(map.keys().skip(1)).all(|(a, b)| a < b));
+
+        let elements: Vec<u8> = bag.iter().copied().collect();
+
+        assert_eq!(bag.len(), elements.len());
+        assert!(is_sorted_asc(&elements));
+
+        let elements: Vec<u8> = chunked.iter().copied().collect();
+
+        assert_eq!(chunked.len(), elements.len());
+        assert!(is_sorted_asc(&elements));
+        assert!((2..=8).contains(&chunked.chunk_size()));
+    }
+}
@@ -0,0 +1,79 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use sorted_insert::*;
+
+#[test]
+fn sorted_drain_range() {
+    let mut v = vec![1, 2, 3, 3, 4, 5, 6];
+
+    assert_eq!(vec![3, 3, 4], v.sorted_drain_range_asc(3..5));
+    assert_eq!([1, 2, 5, 6], v.as_slice());
+    assert_eq!(Vec::<i32>::new(), v.sorted_drain_range_asc(3..5));
+    assert_eq!(vec![5, 6], v.sorted_drain_range_asc(5..));
+
+    let mut v = vec![(6, 'a'), (4, 'b'), (4, 'c'), (2, 'd'), (0, 'e')];
+
+    assert_eq!(
+        vec![(4, 'b'), (4, 'c'), (2, 'd')],
+        v.sorted_drain_range_desc_by_key(1..=4, |e| &e.0)
+    );
+    assert_eq!([(6, 'a'), (0, 'e')], v.as_slice());
+}
+
+#[test]
+fn sorted_retain_range() {
+    let mut v = vec![1, 2, 3, 4, 5];
+
+    v.sorted_retain_range_asc(2..=4);
+    assert_eq!([2, 3, 4], v.as_slice());
+
+    let mut v = vec![5, 4, 3, 2, 1];
+
+    v.sorted_retain_range_desc(..3);
+    assert_eq!([2, 1], v.as_slice());
+}
+
+#[test]
+fn sorted_split_off() {
+    let mut v = vec![1, 2, 2, 3];
+
+    assert_eq!(vec![2, 2, 3], v.sorted_split_off_asc(&2));
+    assert_eq!([1], v.as_slice());
+
+    let mut v = vec![3, 2, 2, 1];
+
+    assert_eq!(vec![2, 2, 1], v.sorted_split_off_desc(&2));
+    assert_eq!([3], v.as_slice());
+}
+
+#[test]
+fn sorted_dedup() {
+    let mut v = vec![(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (3, 'e'), (3, 'f')];
+
+    v.sorted_dedup_by_key(|e| &e.0, DedupKeep::Last);
+    assert_eq!([(1, 'b'), (2, 'c'), (3, 'f')], v.as_slice());
+
+    let mut v = vec![1, 1, 2, 3, 3];
+
+    v.sorted_dedup(DedupKeep::First);
+    assert_eq!([1, 2, 3], v.as_slice());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn bulk_vec_deque() {
+    let mut v: VecDeque<(i32, char)> = vec![(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd')].into();
+
+    v.push_front((0, 'z'));
+
+    v.sorted_dedup_by_key(|e| &e.0, DedupKeep::Last);
+    assert_eq!(vec![(0, 'z'), (1, 'b'), (2, 'c'), (3, 'd')], v.iter().copied().collect::<Vec<_>>());
+
+    let drained = v.sorted_drain_range_asc_by_key(1..3, |e| &e.0);
+    assert_eq!(vec![(1, 'b'), (2, 'c')], drained.into_iter().collect::<Vec<_>>());
+
+    let tail = v.sorted_split_off_asc_by_key(&3, |e| &e.0);
+    assert_eq!(vec![(3, 'd')], tail.into_iter().collect::<Vec<_>>());
+    assert_eq!(vec![(0, 'z')], v.into_iter().collect::<Vec<_>>());
+}
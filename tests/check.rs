@@ -0,0 +1,31 @@
+use sorted_insert::*;
+
+#[test]
+fn check_sorted() {
+    assert_eq!(Ok(()), check_sorted_asc(&Vec::<i32>::new()));
+    assert_eq!(Ok(()), check_sorted_asc(&[1, 1, 2]));
+    assert_eq!(Ok(()), check_sorted_desc(&[2, 1, 1]));
+
+    let error = check_sorted_asc(&[1, 3, 2, 4]).unwrap_err();
+
+    assert_eq!(1, error.index());
+    assert_eq!("the element at index 1 is ordered after the element at index 2", error.to_string());
+
+    assert!(is_sorted_asc_by_key(&[(2, 'a'), (1, 'b')], |e| &e.1));
+    assert!(!is_sorted_desc_by_key(&[(2, 'a'), (1, 'b')], |e| &e.1));
+    assert!(is_sorted_by(&[3, 1, 2], |a: &i32, b: &i32| (a % 3).cmp(&(b % 3))));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_sorted_vec_deque() {
+    use std::collections::VecDeque;
+
+    let mut v: VecDeque<i32> = VecDeque::new();
+
+    v.push_back(2);
+    v.push_front(1);
+    v.push_back(0);
+
+    assert_eq!(1, check_sorted_asc(&v).unwrap_err().index());
+}
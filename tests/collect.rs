@@ -0,0 +1,88 @@
+mod common;
+
+use common::XorShift64;
+use sorted_insert::*;
+
+#[test]
+fn collect_sorted() {
+    assert_eq!(vec![1, 2, 3, 5], vec![3, 1, 5, 2].into_iter().collect_sorted_asc());
+    assert_eq!(vec![5, 3, 2, 1], vec![3, 1, 5, 2].into_iter().collect_sorted_desc());
+
+    // equal keys keep the order they are yielded
+    assert_eq!(
+        vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')],
+        vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]
+            .into_iter()
+            .collect_sorted_asc_by_key(|e| &e.0)
+    );
+    assert_eq!(
+        vec![(2, 'a'), (2, 'c'), (1, 'b'), (1, 'd')],
+        vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]
+            .into_iter()
+            .collect_sorted_desc_by_key(|e| &e.0)
+    );
+}
+
+#[test]
+fn collect_sorted_unique() {
+    assert_eq!(vec![1, 2, 3], vec![3, 1, 3, 2, 1].into_iter().collect_sorted_unique_asc());
+    assert_eq!(vec![3, 2, 1], vec![3, 1, 3, 2, 1].into_iter().collect_sorted_unique_desc());
+
+    // the first one of the elements with equal keys is kept
+    assert_eq!(
+        vec![(1, 'b'), (2, 'a')],
+        vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]
+            .into_iter()
+            .collect_sorted_unique_asc_by_key(|e| &e.0)
+    );
+}
+
+#[test]
+fn sorted_vec_map_from_iter_extend() {
+    let mut map: SortedVecMap<i32, &str> = vec![(2, "b"), (1, "a"), (2, "B")].into_iter().collect();
+
+    assert_eq!([(1, "a"), (2, "B")], map.as_slice());
+
+    map.extend(vec![(3, "c"), (1, "A"), (0, "z"), (3, "C")]);
+
+    assert_eq!([(0, "z"), (1, "A"), (2, "B"), (3, "C")], map.as_slice());
+}
+
+#[test]
+fn sorted_vec_map_repeated_extend() {
+    let mut map: SortedVecMap<u32, u32> = SortedVecMap::new();
+    let mut expected: SortedVecMap<u32, u32> = SortedVecMap::new();
+
+    let mut rng = XorShift64::new(7);
+
+    for i in 0..500 {
+        let batch: Vec<(u32, u32)> =
+            (0..i % 4).map(|j| (rng.next_below(300) as u32, i * 4 + j)).collect();
+
+        for (k, v) in batch.iter().copied() {
+            expected.insert(k, v);
+        }
+
+        map.extend(batch);
+
+        assert_eq!(expected.as_slice(), map.as_slice());
+    }
+
+    // appending keys after the last one
+    map.extend(vec![(1000, 0), (1001, 1), (1000, 2)]);
+
+    assert_eq!(Some(&2), map.get(&1000));
+    assert_eq!(Some(&1001), map.last_key_value().map(|(k, _)| k));
+}
+
+#[test]
+fn sorted_bag_from_iter_extend() {
+    let mut bag: SortedBag<i32> = vec![3, 1, 3, 2].into_iter().collect();
+
+    assert_eq!([(1, 1), (2, 1), (3, 2)], bag.as_slice());
+
+    bag.extend(vec![4, 0, 2, 2, 3]);
+
+    assert_eq!([(0, 1), (1, 1), (2, 3), (3, 3), (4, 1)], bag.as_slice());
+    assert_eq!(9, bag.len());
+}
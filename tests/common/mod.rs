@@ -0,0 +1,52 @@
+//! Fixtures shared by the integration tests.
+
+// each test crate uses only some of the fixtures
+#![allow(dead_code)]
+
+/// A xorshift pseudorandom number generator, so that the tests are reproducible without enabling the `testing` feature.
+pub struct XorShift64 {
+    state: u64,
+}
+
+impl XorShift64 {
+    /// Create a generator from a non-zero seed.
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(0, seed, "xorshift cannot leave the zero state");
+
+        XorShift64 {
+            state: seed
+        }
+    }
+
+    /// Generate the next number.
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+
+        self.state = x;
+
+        x
+    }
+
+    /// Generate a number less than `modulo`.
+    pub fn next_below(&mut self, modulo: u64) -> u64 {
+        self.next_u64() % modulo
+    }
+}
+
+/// Generate `len` pseudorandom numbers less than `modulo`.
+pub fn random_sequence(len: usize, seed: u64, modulo: u64) -> Vec<u64> {
+    let mut rng = XorShift64::new(seed);
+
+    (0..len).map(|_| rng.next_below(modulo)).collect()
+}
+
+/// Generate `len` pseudorandom bytes.
+pub fn random_bytes(len: usize, seed: u64) -> Vec<u8> {
+    let mut rng = XorShift64::new(seed);
+
+    (0..len).map(|_| rng.next_u64() as u8).collect()
+}
@@ -0,0 +1,70 @@
+use core::cmp::Ordering;
+
+use sorted_insert::*;
+
+#[test]
+fn combinators() {
+    let by_len =
+        Natural::new().by_key(|s: &&str| s).then(|a: &&str, b: &&str| a.len().cmp(&b.len()));
+
+    assert_eq!(Ordering::Less, by_len.compare(&"a", &"b"));
+    assert_eq!(Ordering::Greater, by_len.reverse().compare(&"a", &"b"));
+    assert_eq!(Ordering::Less, Order::Descending.compare(&2, &1));
+
+    let nulls_first = Natural::new().nulls_first();
+
+    assert_eq!(Ordering::Less, nulls_first.compare(&None, &Some(0)));
+    assert_eq!(Ordering::Greater, nulls_first.compare(&Some(1), &Some(0)));
+
+    let nulls_last = Natural::new().reverse().nulls_last();
+
+    assert_eq!(Ordering::Greater, nulls_last.compare(&None, &Some(0)));
+    assert_eq!(Ordering::Less, nulls_last.compare(&Some(1), &Some(0)));
+
+    assert_eq!(Ordering::Less, Total::new().compare(&-0.0, &0.0));
+}
+
+#[test]
+fn stored_comparator() {
+    struct Table<C> {
+        rows:       Vec<(Option<u32>, &'static str)>,
+        comparator: C,
+    }
+
+    let mut table = Table {
+        rows:       Vec::new(),
+        comparator: Natural::new()
+            .nulls_last()
+            .by_key(|row: &(Option<u32>, &'static str)| &row.0)
+            .then(Natural::new().by_key(|row: &(Option<u32>, &'static str)| &row.1)),
+    };
+
+    for row in [(Some(2), "b"), (None, "x"), (Some(1), "z"), (Some(2), "a"), (None, "c")] {
+        table.rows.sorted_insert_by_comparator(row, &table.comparator);
+    }
+
+    assert_eq!(
+        [(Some(1), "z"), (Some(2), "a"), (Some(2), "b"), (None, "c"), (None, "x")],
+        table.rows.as_slice()
+    );
+
+    assert_eq!(Ok(2), table.rows.sorted_search_by_comparator(&(Some(2), "b"), &table.comparator));
+    assert_eq!(Err(3), table.rows.sorted_search_by_comparator(&(None, "a"), &table.comparator));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn vec_deque() {
+    use std::collections::VecDeque;
+
+    let comparator = Natural::new().reverse();
+
+    let mut v: VecDeque<i32> = VecDeque::new();
+
+    for e in [1, 3, 2, 3] {
+        v.sorted_insert_binary_by_comparator(e, &comparator);
+    }
+
+    assert_eq!([3, 3, 2, 1], *v.make_contiguous());
+    assert_eq!(Err(4), v.sorted_search_by_comparator(&0, &comparator));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "debug-checks")]
+
+use sorted_insert::*;
+
+#[test]
+fn sorted() {
+    let mut v: Vec<isize> = vec![0, 2, 4];
+
+    v.sorted_insert_asc(3);
+    v.sorted_insert_asc_binary(1);
+    v.sorted_insert_asc_by_key(5, |e| e);
+
+    assert_eq!([0, 1, 2, 3, 4, 5], v.as_slice());
+}
+
+#[test]
+#[should_panic(expected = "the element at index 3 is ordered after the element at index 4")]
+fn not_sorted() {
+    let mut v: Vec<isize> = vec![0, 1, 5, 2, 3];
+
+    v.sorted_insert_asc_binary(4);
+}
+
+#[test]
+fn sorted_strict_predicate() {
+    let mut v: Vec<isize> = vec![1, 2, 3];
+
+    assert_eq!(1, v.sorted_insert_by(2, |e, x| e < x));
+    assert_eq!(4, v.sorted_insert_by(3, |e, x| e <= x));
+
+    assert_eq!([1, 2, 2, 3, 3], v.as_slice());
+}
+
+#[test]
+#[should_panic(expected = "the element at index 1 is ordered after the element at index 2")]
+fn not_sorted_mut() {
+    let mut v: Vec<isize> = vec![0, 5, 1];
+
+    v.sorted_insert_asc_mut(6);
+}
+
+#[test]
+fn sorted_entry() {
+    let mut v: Vec<(isize, char)> = vec![(0, 'a'), (4, 'b')];
+
+    v.sorted_entry_asc_by_key(&2, |e| &e.0).or_insert((2, 'c'));
+
+    assert_eq!([(0, 'a'), (2, 'c'), (4, 'b')], v.as_slice());
+}
+
+#[test]
+#[should_panic(expected = "the element inserted at index 1 does not have the searched key")]
+fn entry_wrong_key() {
+    let mut v: Vec<(isize, char)> = vec![(0, 'a'), (4, 'b')];
+
+    v.sorted_entry_asc_by_key(&2, |e| &e.0).or_insert((5, 'c'));
+}
@@ -0,0 +1,69 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use sorted_insert::*;
+
+#[test]
+fn vec_entry() {
+    let mut v: Vec<(String, u32)> = Vec::new();
+
+    for name in ["b", "a", "c", "a", "b", "a"] {
+        v.sorted_entry_asc_by_key(name, |e| e.0.as_str())
+            .and_modify(|e| e.1 += 1)
+            .or_insert_with(|| (name.to_string(), 1));
+    }
+
+    assert_eq!([("a".to_string(), 3), ("b".to_string(), 2), ("c".to_string(), 1)], v.as_slice());
+
+    match v.sorted_entry_asc_by_key("b", |e| e.0.as_str()) {
+        SortedEntry::Occupied(mut entry) => {
+            assert_eq!(1, entry.index());
+            assert_eq!(2, entry.get().1);
+
+            entry.get_mut().1 = 20;
+        },
+        SortedEntry::Vacant(_) => unreachable!(),
+    }
+
+    match v.sorted_entry_asc_by_key("bb", |e| e.0.as_str()) {
+        SortedEntry::Occupied(_) => unreachable!(),
+        SortedEntry::Vacant(entry) => {
+            assert_eq!(2, entry.index());
+
+            entry.insert(("bb".to_string(), 0)).1 = 5;
+        },
+    }
+
+    assert_eq!(20, v[1].1);
+    assert_eq!(("bb".to_string(), 5), v[2]);
+}
+
+#[test]
+fn vec_entry_desc() {
+    let mut v = vec![(9, 'a'), (5, 'b'), (1, 'c')];
+
+    assert_eq!(0, v.sorted_entry_desc_by_key(&10, |e| &e.0).index());
+    assert_eq!(1, v.sorted_entry_desc_by_key(&5, |e| &e.0).index());
+    assert_eq!(3, v.sorted_entry_desc_by_key(&0, |e| &e.0).index());
+
+    *v.sorted_entry_desc_by_key(&5, |e| &e.0).or_insert((5, 'x')) = (5, 'y');
+    v.sorted_entry_desc_by_key(&3, |e| &e.0).or_insert((3, 'x'));
+
+    assert_eq!([(9, 'a'), (5, 'y'), (3, 'x'), (1, 'c')], v.as_slice());
+
+    let entry = v.sorted_entry_by(|e| e.0.cmp(&0).reverse());
+
+    assert!(matches!(entry, SortedEntry::Vacant(_)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn vec_deque_entry() {
+    let mut v: VecDeque<(u32, u32)> = VecDeque::new();
+
+    for key in [5, 3, 5, 8, 3, 5] {
+        v.sorted_entry_asc_by_key(&key, |e| &e.0).and_modify(|e| e.1 += 1).or_insert((key, 1));
+    }
+
+    assert_eq!([(3, 2), (5, 3), (8, 1)], Vec::from(v).as_slice());
+}
@@ -0,0 +1,78 @@
+use sorted_insert::*;
+
+#[test]
+fn total() {
+    let mut v: Vec<f64> = Vec::new();
+
+    for e in [0.5, f64::NAN, -1.0, 0.0, -0.0, f64::INFINITY] {
+        v.sorted_insert_total_asc(e);
+    }
+
+    assert_eq!(format!("{:?}", v), "[-1.0, -0.0, 0.0, 0.5, inf, NaN]");
+
+    let mut v: Vec<f32> = Vec::new();
+
+    for e in [0.5, f32::NAN, -1.0, 0.0, -0.0, f32::INFINITY] {
+        v.sorted_insert_total_desc_binary(e);
+    }
+
+    assert_eq!(format!("{:?}", v), "[NaN, inf, 0.5, 0.0, -0.0, -1.0]");
+}
+
+#[test]
+fn total_by_key() {
+    let mut v: Vec<(f64, u8)> = Vec::new();
+
+    assert_eq!(0, v.sorted_insert_total_asc_by_key((1.5, 0), |e| &e.0));
+    assert_eq!(0, v.sorted_insert_binary_total_asc_by_key((0.5, 1), |e| &e.0));
+    assert_eq!(2, v.sorted_insert_total_asc_by_key((1.5, 2), |e| &e.0));
+    assert_eq!(vec![(0.5, 1), (1.5, 0), (1.5, 2)], v);
+}
+
+#[test]
+fn partial() {
+    let mut v: Vec<f64> = Vec::new();
+
+    for e in [2.0, 1.0, 3.0] {
+        assert!(v.sorted_insert_partial_asc(e, NanPolicy::Reject).is_ok());
+    }
+
+    assert!(v.sorted_insert_partial_asc(f64::NAN, NanPolicy::Reject).unwrap_err().is_nan());
+    assert_eq!(Ok(0), v.sorted_insert_partial_asc_binary(f64::NAN, NanPolicy::First));
+    assert_eq!(Ok(1), v.sorted_insert_partial_asc(f64::NAN, NanPolicy::First));
+    assert_eq!(Ok(3), v.sorted_insert_partial_asc(1.5, NanPolicy::First));
+    assert_eq!(Ok(4), v.sorted_insert_partial_asc_binary(1.5, NanPolicy::First));
+
+    assert_eq!(format!("{:?}", v), "[NaN, NaN, 1.0, 1.5, 1.5, 2.0, 3.0]");
+
+    let mut v: Vec<f64> = Vec::new();
+
+    for e in [2.0, f64::NAN, 1.0, 3.0] {
+        assert!(v.sorted_insert_partial_desc_binary(e, NanPolicy::Last).is_ok());
+    }
+
+    assert_eq!(format!("{:?}", v), "[3.0, 2.0, 1.0, NaN]");
+
+    for e in [f64::NAN, 2.5] {
+        assert!(v.sorted_insert_partial_desc(e, NanPolicy::Last).is_ok());
+    }
+
+    assert_eq!(format!("{:?}", v), "[3.0, 2.5, 2.0, 1.0, NaN, NaN]");
+}
+
+#[test]
+fn partial_by_key() {
+    let mut v: Vec<(f32, u8)> = Vec::new();
+
+    assert_eq!(Ok(0), v.sorted_insert_partial_desc_by_key((1.0, 0), |e| &e.0, NanPolicy::Reject));
+    assert_eq!(
+        Ok(0),
+        v.sorted_insert_binary_partial_desc_by_key((f32::INFINITY, 1), |e| &e.0, NanPolicy::Reject)
+    );
+    assert_eq!(
+        Err(2),
+        v.sorted_insert_partial_desc_by_key((f32::NAN, 2), |e| &e.0, NanPolicy::Reject)
+            .map_err(|e| e.1)
+    );
+    assert_eq!(vec![(f32::INFINITY, 1), (1.0, 0)], v);
+}
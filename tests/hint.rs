@@ -0,0 +1,75 @@
+use sorted_insert::*;
+
+#[test]
+fn any_hint() {
+    let init: Vec<isize> = vec![0, 2, 2, 4, 6, 8];
+
+    for element in -1..=9 {
+        let mut expected = init.clone();
+        let expected_index = expected.sorted_insert_asc(element);
+
+        for hint in 0..=init.len() + 2 {
+            let mut v = init.clone();
+
+            assert_eq!(expected_index, v.sorted_insert_asc_with_hint(element, hint));
+            assert_eq!(expected, v);
+        }
+    }
+
+    let init: Vec<isize> = init.into_iter().rev().collect();
+
+    for element in -1..=9 {
+        let mut expected = init.clone();
+        let expected_index = expected.sorted_insert_desc(element);
+
+        for hint in 0..=init.len() + 2 {
+            let mut v = init.clone();
+
+            assert_eq!(expected_index, v.sorted_insert_desc_with_hint(element, hint));
+            assert_eq!(expected, v);
+        }
+    }
+}
+
+#[test]
+fn reuse_hint() {
+    let input: [isize; 10] = [1, 2, 4, 3, 5, 5, 7, 6, 8, 9];
+
+    let mut expected: Vec<isize> = Vec::new();
+
+    for e in input {
+        expected.sorted_insert_asc(e);
+    }
+
+    let mut v: Vec<isize> = Vec::new();
+    let mut hint = 0;
+
+    for e in input {
+        hint = v.sorted_insert_asc_with_hint(e, hint);
+    }
+
+    assert_eq!(expected, v);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn vec_deque() {
+    use std::collections::VecDeque;
+
+    let mut v: VecDeque<isize> = VecDeque::new();
+    let mut hint = 0;
+
+    for e in [5, 3, 9, 1, 7] {
+        hint = v.sorted_insert_asc_with_hint(e, hint);
+    }
+
+    assert_eq!([1, 3, 5, 7, 9], *v.make_contiguous());
+
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    struct A(i32, i32);
+
+    let mut v: VecDeque<A> = VecDeque::from(vec![A(1, 30), A(2, 20)]);
+
+    assert_eq!(2, v.sorted_insert_with_hint_desc_by_key(A(3, 20), 0, |e| &e.1));
+    assert_eq!([A(1, 30), A(2, 20), A(3, 20)], *v.make_contiguous());
+}
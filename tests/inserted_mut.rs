@@ -0,0 +1,93 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use sorted_insert::*;
+
+#[test]
+fn vec_insert_mut() {
+    let mut v = vec![1, 3, 5];
+
+    let mut e = v.sorted_insert_asc_mut(4);
+
+    assert_eq!(2, e.index());
+    assert_eq!(Some(&3), e.predecessor());
+    assert_eq!(Some(&5), e.successor());
+
+    *e = 5;
+
+    assert_eq!([1, 3, 5, 5], v.as_slice());
+
+    let e = v.sorted_insert_asc_binary_mut(0);
+
+    assert_eq!(0, e.index());
+    assert_eq!(None, e.predecessor());
+    assert_eq!(Some(&1), e.successor());
+
+    let e = v.sorted_insert_asc_mut(9);
+
+    assert_eq!(5, e.index());
+    assert_eq!(Some(&5), e.predecessor());
+    assert_eq!(None, e.successor());
+
+    let mut v: Vec<u32> = Vec::new();
+
+    let e = v.sorted_insert_desc_binary_mut(7);
+
+    assert_eq!((None, 7, None), (e.predecessor(), *e, e.successor()));
+}
+
+#[test]
+fn vec_insert_by_key_mut() {
+    let mut v = vec![(3, "c"), (1, "a")];
+
+    let e = v.sorted_insert_desc_by_key_mut((2, "b"), |e| &e.0);
+
+    e.into_mut().1 = "B";
+
+    assert_eq!([(3, "c"), (2, "B"), (1, "a")], v.as_slice());
+
+    let mut e = v.sorted_insert_binary_desc_by_key_mut((0, "z"), |e| &e.0);
+
+    assert_eq!(Some(&(1, "a")), e.predecessor());
+
+    e.1 = "Z";
+
+    assert_eq!((0, "Z"), v[3]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn vec_deque_insert_mut() {
+    // make the deque wrap around, so the neighbours can be in the other slice
+    let mut v: VecDeque<u32> = VecDeque::with_capacity(8);
+
+    for e in [4, 6, 8] {
+        v.push_back(e);
+    }
+
+    for e in [2, 0] {
+        v.push_front(e);
+    }
+
+    for element in [1, 3, 5, 7, 9] {
+        let mut w = v.clone();
+
+        let e = w.sorted_insert_asc_mut(element);
+
+        let index = e.index();
+        let neighbours = (e.predecessor().copied(), e.successor().copied());
+
+        let u: Vec<u32> = w.into_iter().collect();
+
+        assert_eq!(element, u[index]);
+        assert_eq!((index.checked_sub(1).map(|i| u[i]), u.get(index + 1).copied()), neighbours);
+    }
+
+    let mut e = v.sorted_insert_asc_binary_mut(5);
+
+    assert_eq!((Some(&4), Some(&6)), (e.predecessor(), e.successor()));
+
+    *e = 6;
+
+    assert_eq!([0, 2, 4, 6, 6, 8], Vec::from(v).as_slice());
+}
@@ -0,0 +1,92 @@
+use core::time::Duration;
+
+use sorted_insert::*;
+
+#[test]
+fn uniform() {
+    let init: Vec<u64> = (0..1000).map(|i| i * 10).collect();
+
+    for element in [0, 1, 5, 10, 4995, 5000, 9990, 9999, 10000, 20000] {
+        let mut expected = init.clone();
+        let expected_index = expected.sorted_insert_asc(element);
+
+        let mut v = init.clone();
+
+        assert_eq!(expected_index, v.sorted_insert_asc_interpolation(element));
+        assert_eq!(expected, v);
+    }
+
+    let init: Vec<u64> = init.into_iter().rev().collect();
+
+    for element in [0, 1, 5, 10, 4995, 5000, 9990, 9999, 10000, 20000] {
+        let mut expected = init.clone();
+        let expected_index = expected.sorted_insert_desc(element);
+
+        let mut v = init.clone();
+
+        assert_eq!(expected_index, v.sorted_insert_desc_interpolation(element));
+        assert_eq!(expected, v);
+    }
+}
+
+#[test]
+fn skewed() {
+    let init: Vec<i64> =
+        (0..63).map(|i| 1 << i).chain(core::iter::repeat_n(i64::MAX, 10)).collect();
+
+    for element in [i64::MIN, 0, 1, 2, 3, 1000, 1 << 40, i64::MAX - 1, i64::MAX] {
+        let mut expected = init.clone();
+        let expected_index = expected.sorted_insert_asc(element);
+
+        let mut v = init.clone();
+
+        assert_eq!(expected_index, v.sorted_insert_asc_interpolation(element));
+        assert_eq!(expected, v);
+    }
+}
+
+#[test]
+fn float() {
+    let mut v: Vec<f64> = Vec::new();
+
+    for e in [0.5, -1.0, f64::INFINITY, 0.0, -0.0, 2.5, f64::NEG_INFINITY, 0.25] {
+        v.sorted_insert_asc_interpolation(e);
+    }
+
+    assert_eq!(vec![f64::NEG_INFINITY, -1.0, -0.0, 0.0, 0.25, 0.5, 2.5, f64::INFINITY], v);
+    assert!(v[2].is_sign_negative());
+
+    assert_eq!(8, v.sorted_insert_asc_interpolation(f64::NAN));
+    assert!(v[8].is_nan());
+}
+
+#[test]
+fn by_key() {
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    struct Sample(Duration, u8);
+
+    let mut v: Vec<Sample> = Vec::new();
+
+    for (i, secs) in [3, 1, 2, 1].iter().enumerate() {
+        v.sorted_insert_interpolation_asc_by_key(
+            Sample(Duration::from_secs(*secs), i as u8),
+            |e| &e.0,
+        );
+    }
+
+    assert_eq!(vec![1, 3, 2, 0], v.iter().map(|e| e.1).collect::<Vec<u8>>());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn vec_deque() {
+    use std::collections::VecDeque;
+
+    let mut v: VecDeque<u32> = VecDeque::new();
+
+    for e in [30, 10, 20, 50, 40] {
+        v.sorted_insert_desc_interpolation(e);
+    }
+
+    assert_eq!([50, 40, 30, 20, 10], *v.make_contiguous());
+}
@@ -0,0 +1,107 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use sorted_insert::*;
+
+#[test]
+fn sorted_merge() {
+    let mut v = vec![1, 3, 5, 7];
+
+    v.sorted_merge_asc(vec![0, 3, 4, 8]);
+
+    assert_eq!([0, 1, 3, 3, 4, 5, 7, 8], v.as_slice());
+
+    let mut v = vec![7, 5, 3];
+
+    v.sorted_merge_desc(vec![6, 5]);
+
+    assert_eq!([7, 6, 5, 5, 3], v.as_slice());
+}
+
+#[test]
+fn sorted_merge_left_first() {
+    let mut v = vec![(1, 'a'), (2, 'a'), (3, 'a')];
+
+    v.sorted_merge_asc_by_key(vec![(0, 'b'), (2, 'b'), (3, 'b')], |e| &e.0);
+
+    assert_eq!([(0, 'b'), (1, 'a'), (2, 'a'), (2, 'b'), (3, 'a'), (3, 'b')], v.as_slice());
+
+    let mut v = vec![(3, 'a'), (1, 'a')];
+
+    v.sorted_merge_dedup_desc_by_key(vec![(3, 'b'), (2, 'b'), (1, 'b')], |e| &e.0);
+
+    assert_eq!([(3, 'a'), (2, 'b'), (1, 'a')], v.as_slice());
+}
+
+#[test]
+fn sorted_merge_dedup() {
+    let mut v = vec![1, 2, 4];
+
+    v.sorted_merge_dedup_asc(vec![2, 3, 4, 4, 5]);
+
+    assert_eq!([1, 2, 3, 4, 5], v.as_slice());
+}
+
+#[test]
+fn sorted_merge_dedup_existing_duplicates() {
+    let mut v = vec![(1, 'a'), (1, 'b'), (3, 'a'), (3, 'b')];
+
+    v.sorted_merge_dedup_asc_by_key(vec![(1, 'c'), (2, 'c')], |e| &e.0);
+
+    assert_eq!([(1, 'a'), (2, 'c'), (3, 'a')], v.as_slice());
+
+    let mut v = vec![2, 2, 4];
+
+    v.sorted_merge_dedup_asc(Vec::new());
+
+    assert_eq!([2, 4], v.as_slice());
+}
+
+#[test]
+fn sorted_merge_interleaved_runs() {
+    let mut v: Vec<(u32, char)> = (0..50).map(|i| (i * 2, 'a')).collect();
+
+    v.sorted_merge_asc_by_key((0..50).map(|i| (i * 3, 'b')), |e| &e.0);
+
+    let mut expected: Vec<(u32, char)> =
+        (0..50).map(|i| (i * 2, 'a')).chain((0..50).map(|i| (i * 3, 'b'))).collect();
+
+    expected.sort_by_key(|e| e.0);
+
+    assert_eq!(expected, v);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sorted_merge_vec_deque() {
+    let mut v = VecDeque::new();
+
+    v.push_back(2);
+    v.push_back(4);
+    v.push_front(1);
+
+    v.sorted_merge_asc(vec![0, 3, 5]);
+
+    assert_eq!(vec![0, 1, 2, 3, 4, 5], v.into_iter().collect::<Vec<i32>>());
+}
+
+#[test]
+fn merge_sorted_iters_stable() {
+    let merged: Vec<(i32, char)> = merge_sorted_iters_by(
+        [vec![(1, 'a'), (3, 'a')], vec![(1, 'b'), (2, 'b')], vec![], vec![(1, 'd'), (3, 'd')]],
+        |a, b| a.0.cmp(&b.0),
+    )
+    .collect();
+
+    assert_eq!(vec![(1, 'a'), (1, 'b'), (1, 'd'), (2, 'b'), (3, 'a'), (3, 'd')], merged);
+}
+
+#[test]
+fn merge_sorted_iters_size_hint() {
+    let mut iter = merge_sorted_iters([1..4, 2..3, 0..0]);
+
+    assert_eq!((4, Some(4)), iter.size_hint());
+    assert_eq!(Some(1), iter.next());
+    assert_eq!((3, Some(3)), iter.size_hint());
+    assert_eq!(vec![2, 2, 3], iter.collect::<Vec<i32>>());
+}
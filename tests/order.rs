@@ -0,0 +1,148 @@
+use sorted_insert::*;
+
+#[test]
+fn order() {
+    assert_eq!(Order::Ascending, Order::default());
+    assert_eq!(Order::Descending, Order::Ascending.reverse());
+    assert!(Order::Descending.is_descending());
+    assert_eq!(core::cmp::Ordering::Greater, Order::Descending.compare(&1, &2));
+}
+
+#[test]
+fn ordered() {
+    for order in [Order::Ascending, Order::Descending] {
+        let mut v: Vec<isize> = Vec::new();
+
+        for e in [3, 1, 2] {
+            v.sorted_insert_ordered(e, order);
+            v.sorted_insert_binary_ordered(e, order);
+            v.sorted_insert_gallop_ordered(e, order);
+        }
+
+        let expected = match order {
+            Order::Ascending => [1, 1, 1, 2, 2, 2, 3, 3, 3],
+            Order::Descending => [3, 3, 3, 2, 2, 2, 1, 1, 1],
+        };
+
+        assert_eq!(expected, v.as_slice());
+
+        let mut v: Vec<u32> = Vec::new();
+        let mut hint = 0;
+
+        for e in [3, 1, 2] {
+            hint = v.sorted_insert_with_hint_ordered(e, hint, order);
+            v.sorted_insert_interpolation_ordered(e, order);
+        }
+
+        let expected = match order {
+            Order::Ascending => [1, 1, 2, 2, 3, 3],
+            Order::Descending => [3, 3, 2, 2, 1, 1],
+        };
+
+        assert_eq!(expected, v.as_slice());
+
+        let mut v: Vec<f64> = Vec::new();
+
+        for e in [3.0, 1.0, f64::NAN, 2.0] {
+            v.sorted_insert_total_ordered(e, order);
+            v.sorted_insert_total_binary_ordered(e, order);
+        }
+
+        for e in [3.0, 1.0, 2.0] {
+            v.sorted_insert_partial_ordered(e, NanPolicy::Last, order).unwrap();
+            v.sorted_insert_partial_binary_ordered(e, NanPolicy::Last, order).unwrap();
+        }
+
+        assert!(v.sorted_insert_partial_ordered(f64::NAN, NanPolicy::Reject, order).is_err());
+
+        let expected = match order {
+            Order::Ascending => [1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 2.0, 3.0, 3.0, 3.0, 3.0],
+            Order::Descending => [3.0, 3.0, 3.0, 3.0, 2.0, 2.0, 2.0, 2.0, 1.0, 1.0, 1.0, 1.0],
+        };
+
+        let numbers: Vec<f64> = v.iter().copied().filter(|e| !e.is_nan()).collect();
+
+        assert_eq!(expected, numbers.as_slice());
+    }
+}
+
+#[test]
+fn ordered_by_key() {
+    #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+    struct A(i32, i32);
+
+    let mut v = vec![A(1, 30), A(2, 10)];
+
+    assert_eq!(1, v.sorted_insert_by_key_ordered(A(3, 20), |e| &e.1, Order::Descending));
+    assert_eq!(1, v.sorted_insert_binary_by_key_ordered(A(4, 25), |e| &e.1, Order::Descending));
+    assert_eq!(4, v.sorted_insert_gallop_by_key_ordered(A(5, 10), |e| &e.1, Order::Descending));
+    assert_eq!([A(1, 30), A(4, 25), A(3, 20), A(2, 10), A(5, 10)], v.as_slice());
+
+    let mut v = vec![A(1, 30), A(2, 10)];
+
+    assert_eq!(
+        1,
+        v.sorted_insert_with_hint_by_key_ordered(A(3, 20), 2, |e| &e.1, Order::Descending)
+    );
+    assert_eq!(
+        1,
+        v.sorted_insert_interpolation_by_key_ordered(A(4, 25), |e| &e.1, Order::Descending)
+    );
+    assert_eq!([A(1, 30), A(4, 25), A(3, 20), A(2, 10)], v.as_slice());
+
+    #[derive(Debug, Copy, Clone, PartialEq)]
+    struct B(i32, f64);
+
+    let mut v = vec![B(1, 3.0), B(2, 1.0)];
+
+    assert_eq!(1, v.sorted_insert_total_by_key_ordered(B(3, 2.0), |e| &e.1, Order::Descending));
+    assert_eq!(
+        2,
+        v.sorted_insert_binary_total_by_key_ordered(B(4, 2.0), |e| &e.1, Order::Descending)
+    );
+    assert_eq!(
+        Ok(4),
+        v.sorted_insert_partial_by_key_ordered(
+            B(5, 1.0),
+            |e| &e.1,
+            NanPolicy::Reject,
+            Order::Descending
+        )
+    );
+    assert_eq!(
+        Ok(0),
+        v.sorted_insert_binary_partial_by_key_ordered(
+            B(6, 4.0),
+            |e| &e.1,
+            NanPolicy::Reject,
+            Order::Descending
+        )
+    );
+    assert_eq!([B(6, 4.0), B(1, 3.0), B(3, 2.0), B(4, 2.0), B(2, 1.0), B(5, 1.0)], v.as_slice());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn ordered_arc_lock() {
+    use std::sync::{Arc, Mutex, RwLock};
+
+    let mut v: Vec<Arc<Mutex<isize>>> = Vec::new();
+
+    for e in [1, 3, 2] {
+        v.sorted_insert_ordered(Arc::new(Mutex::new(e)), Order::Descending);
+        v.sorted_insert_binary_ordered(Arc::new(Mutex::new(e)), Order::Descending);
+    }
+
+    assert_eq!(
+        vec![3, 3, 2, 2, 1, 1],
+        v.iter().map(|e| *e.lock().unwrap()).collect::<Vec<isize>>()
+    );
+
+    let mut v: Vec<Arc<RwLock<isize>>> = Vec::new();
+
+    for e in [1, 3, 2] {
+        v.sorted_insert_gallop_ordered(Arc::new(RwLock::new(e)), Order::Ascending);
+    }
+
+    assert_eq!(vec![1, 2, 3], v.iter().map(|e| *e.read().unwrap()).collect::<Vec<isize>>());
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "rayon")]
+
+mod common;
+
+use common::random_sequence;
+use sorted_insert::*;
+
+#[test]
+fn par_sorted_extend_matches_sorted_insert() {
+    for (existing, batch) in [(0, 10), (10, 0), (100, 100), (50_000, 20_000), (1_000, 60_000)] {
+        let mut v: Vec<(u64, usize)> = Vec::new();
+
+        for (i, e) in random_sequence(existing, 1, 1000).into_iter().enumerate() {
+            v.sorted_insert_asc_by_key((e, i), |e| &e.0);
+        }
+
+        let batch: Vec<(u64, usize)> = random_sequence(batch, 2, 1000)
+            .into_iter()
+            .enumerate()
+            .map(|(i, e)| (e, existing + i))
+            .collect();
+
+        let mut expected = v.clone();
+
+        // equal keys of existing elements come first, then those of the batch in the batch order
+        expected.extend(batch.iter().copied());
+        expected.sort_by_key(|e| e.0);
+
+        v.par_sorted_extend_asc_by_key(batch, |e| &e.0);
+
+        assert_eq!(expected, v);
+    }
+}
+
+#[test]
+fn par_sorted_extend_small() {
+    let mut v = vec![(1, 'a'), (3, 'a')];
+
+    v.par_sorted_extend_by(vec![(3, 'b'), (0, 'b'), (1, 'b'), (1, 'c')], |a, b| a.0.cmp(&b.0));
+
+    let mut expected = vec![(1, 'a'), (3, 'a')];
+
+    for e in [(3, 'b'), (0, 'b'), (1, 'b'), (1, 'c')] {
+        expected.sorted_insert_asc_by_key(e, |e| &e.0);
+    }
+
+    assert_eq!(expected, v);
+
+    let mut v = vec![9, 5, 1];
+
+    v.par_sorted_extend_desc(vec![4, 10, 0]);
+
+    assert_eq!([10, 9, 5, 4, 1, 0], v.as_slice());
+
+    let mut v: Vec<i32> = Vec::new();
+
+    v.par_sorted_extend_asc(vec![3, 1, 2]);
+
+    assert_eq!([1, 2, 3], v.as_slice());
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "proptest")]
+
+use ::proptest::prelude::*;
+use sorted_insert::{proptest::*, *};
+
+proptest! {
+    #[test]
+    fn sorted_vec_is_sorted(v in sorted_vec(0..20u8, 0..50, Order::Ascending), w in sorted_vec(0..20u8, 0..50, Order::Descending)) {
+        prop_assert!(is_sorted_asc(&v));
+        prop_assert!(is_sorted_desc(&w));
+    }
+
+    #[test]
+    fn sorted_unique_vec_is_strictly_sorted(v in sorted_unique_vec(0..100u8, 0..50, Order::Descending)) {
+        prop_assert!(v.windows(2).all(|w| w[0] > w[1]));
+    }
+
+    #[test]
+    fn sorted_vec_with_runs_has_runs(v in sorted_vec_with_runs(0..100u8, 1..10, 1..5usize, Order::Ascending)) {
+        prop_assert!(is_sorted_asc(&v));
+        prop_assert!(!v.is_empty());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sorted_vec_deque_is_sorted(v in sorted_vec_deque(any::<i16>(), 0..50, Order::Descending)) {
+        let v: Vec<i16> = v.into_iter().collect();
+
+        prop_assert!(is_sorted_desc(&v));
+    }
+
+    #[test]
+    fn insertion_sequence_matches_sort(s in insertion_sequence(any::<u32>(), 1..5, 0..100)) {
+        let mut v: Vec<u32> = Vec::new();
+
+        for e in s.iter().copied() {
+            v.sorted_insert_asc_binary(e);
+        }
+
+        let mut expected = s;
+        expected.sort_unstable();
+
+        prop_assert_eq!(expected, v);
+    }
+
+    #[test]
+    fn wrapper_types_are_consistent(
+        map in any::<SortedVecMap<u8, u8>>(),
+        bag in any::<SortedBag<u8>>(),
+        chunked in sorted_chunked_vec(any::<u8>(), 0..3000),
+    ) {
+        prop_assert!(map.keys().zip(map.keys().skip(1)).all(|(a, b)| a < b));
+
+        let v: Vec<u8> = bag.iter().copied().collect();
+
+        prop_assert_eq!(bag.len(), v.len());
+        prop_assert!(is_sorted_asc(&v));
+
+        let v: Vec<u8> = chunked.iter().copied().collect();
+
+        prop_assert_eq!(chunked.len(), v.len());
+        prop_assert!(is_sorted_asc(&v));
+        prop_assert!((2..=8).contains(&chunked.chunk_size()));
+    }
+}
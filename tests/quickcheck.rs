@@ -0,0 +1,59 @@
+#![cfg(feature = "quickcheck")]
+
+use ::quickcheck::{quickcheck, Arbitrary};
+use sorted_insert::{quickcheck::*, *};
+
+quickcheck! {
+    fn sorted_vec_is_sorted(v: SortedAscVec<i32>, w: SortedDescVec<i32>) -> bool {
+        is_sorted_asc(&v.0) && is_sorted_desc(&w.0)
+    }
+
+    fn sorted_unique_vec_is_strictly_sorted(v: SortedUniqueAscVec<u8>) -> bool {
+        v.0.windows(2).all(|w| w[0] < w[1])
+    }
+
+    #[cfg(feature = "std")]
+    fn sorted_vec_deque_insert_keeps_order(v: SortedDescVecDeque<u16>, e: u16) -> bool {
+        let mut v = v.0;
+
+        v.sorted_insert_desc_binary(e);
+
+        v.iter().zip(v.iter().skip(1)).all(|(a, b)| a >= b)
+    }
+
+    fn insertion_sequence_matches_sort(s: InsertionSequence<u32>) -> bool {
+        let mut v: Vec<u32> = Vec::new();
+
+        for e in s.0.iter().copied() {
+            v.sorted_insert_asc(e);
+        }
+
+        let mut expected = s.0;
+        expected.sort_unstable();
+
+        expected == v
+    }
+
+    fn wrapper_types_are_consistent(map: SortedVecMap<u8, u8>, bag: SortedBag<u8>, chunked: SortedChunkedVec<u8>) -> bool {
+        let bag_elements: Vec<u8> = bag.iter().copied().collect();
+        let chunked_elements: Vec<u8> = chunked.iter().copied().collect();
+
+        map.keys().zip(map.keys().skip(1)).all(|(a, b)| a < b)
+            && bag.len() == bag_elements.len()
+            && is_sorted_asc(&bag_elements)
+            && chunked.len() == chunked_elements.len()
+            && is_sorted_asc(&chunked_elements)
+            && (2..=8).contains(&chunked.chunk_size())
+    }
+}
+
+#[test]
+fn shrink_keeps_order() {
+    let v = SortedDescVec(vec![9, 7, 7, 3, 1]);
+
+    assert!(v.shrink().all(|w| is_sorted_desc(&w.0)));
+
+    let v = SortedUniqueAscVec(vec![1, 3, 7, 9]);
+
+    assert!(v.shrink().all(|w| w.0.windows(2).all(|w| w[0] < w[1])));
+}
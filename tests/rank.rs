@@ -0,0 +1,84 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use sorted_insert::*;
+
+#[test]
+fn rank_and_count() {
+    let v = vec![10, 20, 20, 30, 40];
+
+    assert_eq!(1, v.sorted_rank_asc(&20));
+    assert_eq!(3, v.sorted_rank_asc(&25));
+    assert_eq!(3, v.sorted_count_less_than_asc(&30));
+    assert_eq!(3, v.sorted_count_in_range_asc(20..=30));
+    assert_eq!(0, v.sorted_count_in_range_asc(41..));
+
+    let v = vec![40, 30, 20, 20, 10];
+
+    assert_eq!(2, v.sorted_rank_desc(&20));
+    assert_eq!(0, v.sorted_rank_desc(&50));
+    assert_eq!(1, v.sorted_count_less_than_desc(&20));
+    assert_eq!(4, v.sorted_count_in_range_desc(15..));
+}
+
+#[test]
+fn rank_by_key() {
+    // a leaderboard sorted by scores in descending order
+    let mut leaderboard: Vec<(&str, i32)> = Vec::new();
+
+    for (name, score) in [("a", 70), ("b", 90), ("c", 80), ("d", 90)] {
+        leaderboard.sorted_insert_binary_desc_by_key((name, score), |e| &e.1);
+    }
+
+    assert_eq!(0, leaderboard.sorted_rank_desc_by_key(&90, |e| &e.1));
+    assert_eq!(2, leaderboard.sorted_rank_desc_by_key(&80, |e| &e.1));
+    assert_eq!(2, leaderboard.sorted_count_less_than_desc_by_key(&90, |e| &e.1));
+    assert_eq!(1, leaderboard.sorted_count_in_range_desc_by_key(75..85, |e| &e.1));
+    assert_eq!(2, leaderboard.sorted_rank_by(|e| 85.cmp(&e.1)));
+}
+
+#[test]
+fn percentile_and_median() {
+    let v: Vec<i32> = (1..=10).collect();
+
+    assert_eq!(Some(&1), v.sorted_percentile_asc(0.0));
+    assert_eq!(Some(&3), v.sorted_percentile_asc(25.0));
+    assert_eq!(Some(&9), v.sorted_percentile_asc(90.0));
+    assert_eq!(Some(&10), v.sorted_percentile_asc(90.1));
+    assert_eq!(Some(&10), v.sorted_percentile_asc(100.0));
+    assert_eq!(Some(&5), v.sorted_median_asc());
+
+    let v: Vec<i32> = (1..=5).rev().collect();
+
+    assert_eq!(Some(&2), v.sorted_percentile_desc(40.0));
+    assert_eq!(Some(&3), v.sorted_median_desc());
+
+    let v: Vec<i32> = vec![4, 3, 2, 1];
+
+    assert_eq!(Some(&2), v.sorted_median_desc());
+
+    let v: Vec<i32> = Vec::new();
+
+    assert_eq!(None, v.sorted_percentile_asc(50.0));
+    assert_eq!(None, v.sorted_median_asc());
+}
+
+#[test]
+#[should_panic]
+fn percentile_out_of_range() {
+    vec![1].sorted_percentile_asc(100.5);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn rank_vec_deque() {
+    let mut v: VecDeque<i32> = VecDeque::new();
+
+    for e in [3, 1, 2, 5, 4] {
+        v.sorted_insert_asc_binary(e);
+    }
+
+    assert_eq!(2, v.sorted_rank_asc(&3));
+    assert_eq!(Some(&3), v.sorted_median_asc());
+    assert_eq!(Some(&4), v.sorted_percentile_asc(80.0));
+}
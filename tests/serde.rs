@@ -0,0 +1,97 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use sorted_insert::{SortedBag, SortedChunkedVec, SortedVecMap};
+
+#[test]
+fn sorted_vec_map() {
+    let mut map = SortedVecMap::new();
+
+    map.insert(2, "b".to_string());
+    map.insert(1, "a".to_string());
+
+    let json = serde_json::to_string(&map).unwrap();
+
+    assert_eq!(r#"{"1":"a","2":"b"}"#, json);
+    assert_eq!(map, serde_json::from_str::<SortedVecMap<i32, String>>(&json).unwrap());
+
+    let error = serde_json::from_str::<SortedVecMap<i32, String>>(r#"{"2":"b","1":"a"}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.starts_with("the key at index 0 is not less than the key at index 1"));
+    assert!(serde_json::from_str::<SortedVecMap<i32, i32>>(r#"{"1":1,"1":2}"#).is_err());
+}
+
+#[test]
+fn sorted_bag() {
+    let bag: SortedBag<i32> = vec![3, 1, 3].into_iter().collect();
+
+    let json = serde_json::to_string(&bag).unwrap();
+
+    assert_eq!("[[1,1],[3,2]]", json);
+    assert_eq!(bag, serde_json::from_str::<SortedBag<i32>>(&json).unwrap());
+
+    assert!(serde_json::from_str::<SortedBag<i32>>("[[3,2],[1,1]]").is_err());
+    assert!(serde_json::from_str::<SortedBag<i32>>("[[1,0]]").is_err());
+}
+
+#[test]
+fn sorted_chunked_vec() {
+    let v: SortedChunkedVec<i32> = (0..10).rev().collect();
+
+    let json = serde_json::to_string(&v).unwrap();
+
+    assert_eq!("[0,1,2,3,4,5,6,7,8,9]", json);
+    assert_eq!(v, serde_json::from_str::<SortedChunkedVec<i32>>(&json).unwrap());
+
+    let error = serde_json::from_str::<SortedChunkedVec<i32>>("[0,2,1]").unwrap_err().to_string();
+
+    assert!(error.starts_with("the element at index 1 is ordered after the element at index 2"));
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Resorted {
+    #[serde(with = "sorted_insert::serde::resort")]
+    map:  SortedVecMap<i32, i32>,
+    #[serde(with = "sorted_insert::serde::resort")]
+    bag:  SortedBag<i32>,
+    #[serde(with = "sorted_insert::serde::resort")]
+    v:    SortedChunkedVec<i32>,
+    #[serde(with = "sorted_insert::serde::asc_resort")]
+    asc:  Vec<i32>,
+    #[serde(with = "sorted_insert::serde::desc_resort")]
+    desc: Vec<i32>,
+}
+
+#[test]
+fn resort() {
+    let resorted: Resorted = serde_json::from_str(
+        r#"{"map":{"2":20,"1":10,"2":21},"bag":[[3,1],[1,1],[3,2],[2,0]],"v":[2,0,1],"asc":[2,0,1],"desc":[2,0,1]}"#,
+    )
+    .unwrap();
+
+    assert_eq!([(1, 10), (2, 21)], resorted.map.as_slice());
+    assert_eq!([(1, 1), (3, 3)], resorted.bag.as_slice());
+    assert_eq!(vec![0, 1, 2], resorted.v.into_vec());
+    assert_eq!([0, 1, 2], resorted.asc.as_slice());
+    assert_eq!([2, 1, 0], resorted.desc.as_slice());
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Checked {
+    #[serde(with = "sorted_insert::serde::asc")]
+    asc:  Vec<i32>,
+    #[serde(with = "sorted_insert::serde::desc")]
+    desc: Vec<i32>,
+}
+
+#[test]
+fn checked_vec() {
+    let checked: Checked = serde_json::from_str(r#"{"asc":[0,1,1],"desc":[2,2,0]}"#).unwrap();
+
+    assert_eq!(r#"{"asc":[0,1,1],"desc":[2,2,0]}"#, serde_json::to_string(&checked).unwrap());
+
+    assert!(serde_json::from_str::<Checked>(r#"{"asc":[1,0],"desc":[]}"#).is_err());
+    assert!(serde_json::from_str::<Checked>(r#"{"asc":[],"desc":[0,1]}"#).is_err());
+}
@@ -0,0 +1,111 @@
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use sorted_insert::*;
+
+#[test]
+fn set_operation_iter_asc() {
+    let a = [1, 2, 2, 2, 4, 6];
+    let b = vec![2, 3, 4, 4, 7];
+
+    assert_eq!(
+        vec![&1, &2, &2, &2, &3, &4, &4, &6, &7],
+        a.sorted_union_asc(&b).collect::<Vec<_>>()
+    );
+    assert_eq!(vec![&2, &4], a.sorted_intersection_asc(&b).collect::<Vec<_>>());
+    assert_eq!(vec![&1, &2, &2, &6], a.sorted_difference_asc(&b).collect::<Vec<_>>());
+    assert_eq!(
+        vec![&1, &2, &2, &3, &4, &6, &7],
+        a.sorted_symmetric_difference_asc(&b).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn set_operation_iter_desc_by_key() {
+    let a = [(5, 'a'), (3, 'a'), (1, 'a')];
+    let b = vec![(4, 'b'), (3, 'b'), (0, 'b')];
+
+    assert_eq!(
+        vec![&(5, 'a'), &(4, 'b'), &(3, 'a'), &(1, 'a'), &(0, 'b')],
+        a.sorted_union_desc_by_key(&b, |e| &e.0).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![&(3, 'a')],
+        a.sorted_intersection_desc_by_key(&b, |e| &e.0).collect::<Vec<_>>()
+    );
+    assert_eq!(vec![&3, &1], [5, 3, 1].sorted_difference_desc(&[5, 4]).collect::<Vec<_>>());
+}
+
+#[test]
+fn set_operation_iter_by_comparator() {
+    let comparator = |a: &i32, b: &i32| a.abs().cmp(&b.abs());
+
+    let a = [-1, 2, -3];
+    let b = [1, -3, 4];
+
+    assert_eq!(vec![&2, &4], a.sorted_symmetric_difference_by(&b, comparator).collect::<Vec<_>>());
+
+    let mut iter = a.sorted_union_by(&b, comparator);
+
+    assert_eq!((3, Some(6)), iter.size_hint());
+    assert_eq!(vec![&-1, &2, &-3, &4], iter.by_ref().collect::<Vec<_>>());
+    assert_eq!(None, iter.next());
+}
+
+#[test]
+fn set_operation_in_place() {
+    let mut v = vec![1, 2, 2, 4];
+
+    v.sorted_union_in_place_asc(vec![2, 3, 5]);
+    assert_eq!([1, 2, 2, 3, 4, 5], v.as_slice());
+
+    v.sorted_intersection_in_place_asc(vec![0, 2, 3, 3, 5]);
+    assert_eq!([2, 3, 5], v.as_slice());
+
+    v.sorted_difference_in_place_asc(vec![3]);
+    assert_eq!([2, 5], v.as_slice());
+
+    v.sorted_symmetric_difference_in_place_asc(vec![1, 5, 6]);
+    assert_eq!([1, 2, 6], v.as_slice());
+
+    let mut v = vec![(3, 'a'), (2, 'a')];
+
+    v.sorted_union_in_place_desc_by_key(vec![(3, 'b'), (1, 'b')], |e| &e.0);
+    assert_eq!([(3, 'a'), (2, 'a'), (1, 'b')], v.as_slice());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn set_operation_iter_vec_deque() {
+    let mut a: VecDeque<i32> = VecDeque::with_capacity(4);
+
+    a.push_back(4);
+    a.push_back(6);
+    a.push_front(2);
+    a.push_front(1);
+
+    let b: VecDeque<i32> = vec![2, 3, 6, 7].into();
+
+    assert_eq!(vec![&1, &2, &3, &4, &6, &7], a.sorted_union_asc(&b).collect::<Vec<_>>());
+    assert_eq!(vec![&2, &6], a.sorted_intersection_asc(&b).collect::<Vec<_>>());
+    assert_eq!(vec![&1, &4], a.sorted_difference_asc(&b).collect::<Vec<_>>());
+    assert_eq!(
+        vec![&7, &4, &3, &1],
+        b.iter()
+            .rev()
+            .copied()
+            .collect::<VecDeque<_>>()
+            .sorted_symmetric_difference_desc(&a.iter().rev().copied().collect())
+            .collect::<Vec<_>>()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn set_operation_in_place_vec_deque() {
+    let mut v: VecDeque<i32> = vec![1, 2, 3, 4].into();
+
+    v.sorted_intersection_in_place_asc(vec![2, 4, 6]);
+
+    assert_eq!(vec![2, 4], v.into_iter().collect::<Vec<i32>>());
+}
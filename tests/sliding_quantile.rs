@@ -0,0 +1,108 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::random_sequence;
+use sorted_insert::*;
+
+#[test]
+fn matches_sorting_the_window() {
+    for (window_size, modulo) in [(1, 10), (7, 5), (100, 1000), (3000, 50)] {
+        let elements = random_sequence(10_000, window_size as u64, modulo);
+
+        let mut tracker = SlidingQuantile::new(window_size);
+
+        for (i, e) in elements.iter().copied().enumerate() {
+            let evicted = tracker.push(e);
+
+            if i >= window_size {
+                assert_eq!(Some(elements[i - window_size]), evicted);
+            } else {
+                assert_eq!(None, evicted);
+            }
+
+            if i % 97 != 0 {
+                continue;
+            }
+
+            let mut window: Vec<u64> = elements[(i + 1).saturating_sub(window_size)..=i].to_vec();
+
+            window.sort_unstable();
+
+            assert_eq!(window.sorted_median_asc(), tracker.median());
+            assert_eq!(window.first(), tracker.min());
+            assert_eq!(window.last(), tracker.max());
+
+            for q in [0.0, 0.1, 0.25, 0.5, 0.9, 0.99, 1.0] {
+                assert_eq!(window.sorted_percentile_asc(q * 100.0), tracker.quantile(q));
+            }
+
+            assert_eq!(window, tracker.iter_sorted().copied().collect::<Vec<u64>>());
+        }
+    }
+}
+
+#[test]
+fn floats() {
+    let mut tracker = SlidingQuantile::new_total(4);
+
+    for e in [0.5, -0.0, 0.0, f64::NAN, 2.0, -3.5] {
+        tracker.push(e);
+    }
+
+    assert!(tracker.is_full());
+    assert_eq!(Some(&-3.5), tracker.min());
+    assert!(tracker.max().unwrap().is_nan());
+    assert_eq!(Some(&0.0), tracker.median());
+    assert_eq!(4, tracker.iter().count());
+
+    assert_eq!(Some(0.0), tracker.pop_oldest());
+    assert_eq!(Some(&2.0), tracker.median());
+}
+
+#[test]
+fn comparator() {
+    let mut tracker = SlidingQuantile::with_comparator(3, Order::Descending);
+
+    for e in [1, 5, 3, 4] {
+        tracker.push(e);
+    }
+
+    assert_eq!(Some(&5), tracker.quantile(0.0));
+    assert_eq!(Some(&4), tracker.median());
+
+    tracker.clear();
+
+    assert!(tracker.is_empty());
+    assert_eq!(None, tracker.median());
+}
+
+#[test]
+fn comparator_by_key() {
+    let mut tracker =
+        SlidingQuantile::with_comparator(2, Natural::new().by_key(|e: &(u32, char)| &e.0));
+
+    tracker.push((1, 'a'));
+    tracker.push((1, 'b'));
+
+    // equal keys are sorted in their arrival order
+    assert_eq!(vec![&(1, 'a'), &(1, 'b')], tracker.iter_sorted().collect::<Vec<_>>());
+
+    // the oldest element leaves the window, not any element with an equal key
+    assert_eq!(Some((1, 'a')), tracker.push((5, 'c')));
+    assert_eq!(vec![&(1, 'b'), &(5, 'c')], tracker.iter().collect::<Vec<_>>());
+    assert_eq!(vec![&(1, 'b'), &(5, 'c')], tracker.iter_sorted().collect::<Vec<_>>());
+
+    assert_eq!(Some((1, 'b')), tracker.push((1, 'd')));
+    assert_eq!(Some(&(1, 'd')), tracker.min());
+    assert_eq!(Some(&(5, 'c')), tracker.max());
+    assert_eq!(vec![&(5, 'c'), &(1, 'd')], tracker.iter_sorted().rev().collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic]
+fn invalid_quantile() {
+    let tracker: SlidingQuantile<u32> = SlidingQuantile::new(1);
+
+    tracker.quantile(1.5);
+}
@@ -22,6 +22,11 @@ fn rc() {
 
     let mut v: Vec<Rc<isize>> = Vec::new();
 
+    assert_eq!(0, v.sorted_insert_asc_gallop(Rc::new(1)));
+    assert_eq!([Rc::new(1)], v.as_slice());
+
+    let mut v: Vec<Rc<isize>> = Vec::new();
+
     assert_eq!(0, v.sorted_insert_desc(Rc::new(1)));
     assert_eq!([Rc::new(1)], v.as_slice());
 
@@ -29,6 +34,11 @@ fn rc() {
 
     assert_eq!(0, v.sorted_insert_desc_binary(Rc::new(1)));
     assert_eq!([Rc::new(1)], v.as_slice());
+
+    let mut v: Vec<Rc<isize>> = Vec::new();
+
+    assert_eq!(0, v.sorted_insert_desc_gallop(Rc::new(1)));
+    assert_eq!([Rc::new(1)], v.as_slice());
 }
 
 #[test]
@@ -45,6 +55,11 @@ fn rc_refcell() {
 
     let mut v: Vec<Rc<RefCell<isize>>> = Vec::new();
 
+    assert_eq!(0, v.sorted_insert_asc_gallop(Rc::new(RefCell::new(1))));
+    assert_eq!([Rc::new(RefCell::new(1))], v.as_slice());
+
+    let mut v: Vec<Rc<RefCell<isize>>> = Vec::new();
+
     assert_eq!(0, v.sorted_insert_desc(Rc::new(RefCell::new(1))));
     assert_eq!([Rc::new(RefCell::new(1))], v.as_slice());
 
@@ -52,6 +67,11 @@ fn rc_refcell() {
 
     assert_eq!(0, v.sorted_insert_desc_binary(Rc::new(RefCell::new(1))));
     assert_eq!([Rc::new(RefCell::new(1))], v.as_slice());
+
+    let mut v: Vec<Rc<RefCell<isize>>> = Vec::new();
+
+    assert_eq!(0, v.sorted_insert_desc_gallop(Rc::new(RefCell::new(1))));
+    assert_eq!([Rc::new(RefCell::new(1))], v.as_slice());
 }
 
 #[test]
@@ -68,6 +88,11 @@ fn arc() {
 
     let mut v: Vec<Arc<isize>> = Vec::new();
 
+    assert_eq!(0, v.sorted_insert_asc_gallop(Arc::new(1)));
+    assert_eq!([Arc::new(1)], v.as_slice());
+
+    let mut v: Vec<Arc<isize>> = Vec::new();
+
     assert_eq!(0, v.sorted_insert_desc(Arc::new(1)));
     assert_eq!([Arc::new(1)], v.as_slice());
 
@@ -75,6 +100,11 @@ fn arc() {
 
     assert_eq!(0, v.sorted_insert_desc_binary(Arc::new(1)));
     assert_eq!([Arc::new(1)], v.as_slice());
+
+    let mut v: Vec<Arc<isize>> = Vec::new();
+
+    assert_eq!(0, v.sorted_insert_desc_gallop(Arc::new(1)));
+    assert_eq!([Arc::new(1)], v.as_slice());
 }
 
 #[test]
@@ -91,6 +121,11 @@ fn arc_mutex() {
 
     let mut v: Vec<Arc<Mutex<isize>>> = Vec::new();
 
+    assert_eq!(0, v.sorted_insert_asc_gallop(Arc::new(Mutex::new(1))));
+    assert_eq!(vec![1], v.as_slice().iter().map(|e| *e.lock().unwrap()).collect::<Vec<isize>>());
+
+    let mut v: Vec<Arc<Mutex<isize>>> = Vec::new();
+
     assert_eq!(0, v.sorted_insert_desc(Arc::new(Mutex::new(1))));
     assert_eq!(vec![1], v.as_slice().iter().map(|e| *e.lock().unwrap()).collect::<Vec<isize>>());
 
@@ -98,6 +133,11 @@ fn arc_mutex() {
 
     assert_eq!(0, v.sorted_insert_desc_binary(Arc::new(Mutex::new(1))));
     assert_eq!(vec![1], v.as_slice().iter().map(|e| *e.lock().unwrap()).collect::<Vec<isize>>());
+
+    let mut v: Vec<Arc<Mutex<isize>>> = Vec::new();
+
+    assert_eq!(0, v.sorted_insert_desc_gallop(Arc::new(Mutex::new(1))));
+    assert_eq!(vec![1], v.as_slice().iter().map(|e| *e.lock().unwrap()).collect::<Vec<isize>>());
 }
 
 #[test]
@@ -114,6 +154,11 @@ fn arc_rw_lock() {
 
     let mut v: Vec<Arc<RwLock<isize>>> = Vec::new();
 
+    assert_eq!(0, v.sorted_insert_asc_gallop(Arc::new(RwLock::new(1))));
+    assert_eq!(vec![1], v.as_slice().iter().map(|e| *e.read().unwrap()).collect::<Vec<isize>>());
+
+    let mut v: Vec<Arc<RwLock<isize>>> = Vec::new();
+
     assert_eq!(0, v.sorted_insert_desc(Arc::new(RwLock::new(1))));
     assert_eq!(vec![1], v.as_slice().iter().map(|e| *e.read().unwrap()).collect::<Vec<isize>>());
 
@@ -121,4 +166,9 @@ fn arc_rw_lock() {
 
     assert_eq!(0, v.sorted_insert_desc_binary(Arc::new(RwLock::new(1))));
     assert_eq!(vec![1], v.as_slice().iter().map(|e| *e.read().unwrap()).collect::<Vec<isize>>());
+
+    let mut v: Vec<Arc<RwLock<isize>>> = Vec::new();
+
+    assert_eq!(0, v.sorted_insert_desc_gallop(Arc::new(RwLock::new(1))));
+    assert_eq!(vec![1], v.as_slice().iter().map(|e| *e.read().unwrap()).collect::<Vec<isize>>());
 }
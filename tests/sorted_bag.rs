@@ -0,0 +1,115 @@
+use sorted_insert::SortedBag;
+
+#[test]
+fn insert_remove() {
+    let mut bag = SortedBag::new();
+
+    assert_eq!(1, bag.insert(5));
+    assert_eq!(1, bag.insert(2));
+    assert_eq!(2, bag.insert(5));
+    assert_eq!(4, bag.insert_many(2, 3));
+    assert_eq!(0, bag.insert_many(9, 0));
+
+    assert_eq!(6, bag.len());
+    assert_eq!(2, bag.distinct_len());
+    assert_eq!([(2, 4), (5, 2)], bag.as_slice());
+
+    assert!(bag.remove(&5));
+    assert_eq!(1, bag.count(&5));
+    assert!(bag.remove(&5));
+    assert!(!bag.remove(&5));
+    assert!(!bag.contains(&5));
+
+    assert_eq!(3, bag.remove_many(&2, 3));
+    assert_eq!(1, bag.remove_all(&2));
+    assert!(bag.is_empty());
+    assert_eq!(0, bag.distinct_len());
+}
+
+#[test]
+fn rank_select() {
+    let mut bag = SortedBag::new();
+
+    for e in [3, 1, 3, 2, 3, 1] {
+        bag.insert(e);
+    }
+
+    assert_eq!(0, bag.rank(&0));
+    assert_eq!(0, bag.rank(&1));
+    assert_eq!(2, bag.rank(&2));
+    assert_eq!(3, bag.rank(&3));
+    assert_eq!(6, bag.rank(&4));
+
+    let expected = [1, 1, 2, 3, 3, 3];
+
+    for (i, e) in expected.iter().enumerate() {
+        assert_eq!(Some(e), bag.select(i));
+    }
+
+    assert_eq!(None, bag.select(6));
+}
+
+#[test]
+fn iter() {
+    let mut bag = SortedBag::new();
+
+    for e in [3, 1, 3, 2, 3, 1] {
+        bag.insert(e);
+    }
+
+    assert_eq!(vec![1, 1, 2, 3, 3, 3], bag.iter().copied().collect::<Vec<i32>>());
+    assert_eq!(vec![3, 3, 3, 2, 1, 1], bag.iter().rev().copied().collect::<Vec<i32>>());
+    assert_eq!(vec![(&1, 2), (&2, 1), (&3, 3)], bag.runs().collect::<Vec<(&i32, usize)>>());
+
+    let mut iter = bag.iter();
+
+    assert_eq!(Some(&1), iter.next());
+    assert_eq!(Some(&3), iter.next_back());
+    assert_eq!(Some(&1), iter.next());
+    assert_eq!(Some(&3), iter.next_back());
+    assert_eq!(Some(&2), iter.next());
+    assert_eq!(1, iter.len());
+    assert_eq!(Some(&3), iter.next());
+    assert_eq!(None, iter.next());
+    assert_eq!(None, iter.next_back());
+}
+
+#[test]
+fn rank_select_after_updates() {
+    let mut bag = SortedBag::new();
+    let mut expected: Vec<u32> = Vec::new();
+
+    for i in 0..600u32 {
+        let e = i.wrapping_mul(7919) % 37;
+
+        if i % 3 == 2 {
+            let removed = bag.remove_many(&e, 2);
+
+            for _ in 0..removed {
+                let index = expected.iter().position(|x| *x == e).unwrap();
+
+                expected.remove(index);
+            }
+        } else {
+            bag.insert_many(e, (i % 2 + 1) as usize);
+
+            for _ in 0..i % 2 + 1 {
+                let index = expected.partition_point(|x| *x <= e);
+
+                expected.insert(index, e);
+            }
+        }
+
+        assert_eq!(expected.len(), bag.len());
+
+        for e in 0..38 {
+            assert_eq!(expected.partition_point(|x| *x < e), bag.rank(&e));
+        }
+
+        for (index, e) in expected.iter().enumerate() {
+            assert_eq!(Some(e), bag.select(index));
+        }
+
+        assert_eq!(None, bag.select(expected.len()));
+    }
+}
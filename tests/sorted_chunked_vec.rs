@@ -0,0 +1,168 @@
+mod common;
+
+use common::XorShift64;
+use sorted_insert::*;
+
+#[test]
+fn insert_matches_vec() {
+    let mut chunked = SortedChunkedVec::with_chunk_size(4);
+    let mut v: Vec<u32> = Vec::new();
+
+    let mut rng = XorShift64::new(12345);
+
+    for _ in 0..200 {
+        let e = rng.next_below(50) as u32;
+
+        assert_eq!(v.sorted_insert_asc(e), chunked.sorted_insert_asc(e));
+        chunked.sorted_insert_asc_binary(e);
+        v.sorted_insert_asc_binary(e);
+    }
+
+    assert!(chunked.chunk_count() > 50);
+    assert_eq!(v, chunked.iter().copied().collect::<Vec<u32>>());
+    assert_eq!(v.iter().rev().collect::<Vec<_>>(), chunked.iter().rev().collect::<Vec<_>>());
+
+    for (i, e) in v.iter().enumerate() {
+        assert_eq!(Some(e), chunked.get(i));
+        assert_eq!(e, &chunked[i]);
+    }
+
+    assert_eq!(v.partition_point(|e| *e < 25), chunked.partition_point(|e| *e < 25));
+    assert_eq!(v.sorted_rank_asc(&30), chunked.sorted_rank_asc(&30));
+    assert_eq!(Err(v.len()), chunked.binary_search(&50));
+    assert!(chunked.contains(&v[7]));
+}
+
+#[test]
+fn remove_merges_chunks() {
+    let mut chunked = SortedChunkedVec::with_chunk_size(8);
+
+    for e in 0..100 {
+        chunked.sorted_insert_desc_binary(e);
+    }
+
+    let chunks = chunked.chunk_count();
+
+    for i in (0..90).rev() {
+        let index = chunked.len() - 1 - i % chunked.len();
+        let expected = chunked[index];
+
+        assert_eq!(expected, chunked.remove(index));
+    }
+
+    assert_eq!(10, chunked.len());
+    assert!(chunked.chunk_count() < chunks);
+    assert!(chunked.iter().zip(chunked.iter().skip(1)).all(|(a, b)| a >= b));
+
+    while chunked.pop().is_some() {}
+
+    assert!(chunked.is_empty());
+    assert_eq!(0, chunked.chunk_count());
+}
+
+#[test]
+fn from_and_into() {
+    let chunked: SortedChunkedVec<i32> = (0..3000).rev().collect();
+
+    assert_eq!(3000, chunked.len());
+    assert_eq!(Some(&0), chunked.first());
+    assert_eq!(Some(&2999), chunked.last());
+    assert_eq!(Ok(1234), chunked.binary_search(&1234));
+
+    let mut iter = chunked.clone().into_iter();
+
+    assert_eq!(3000, iter.len());
+    assert_eq!(Some(0), iter.next());
+    assert_eq!(Some(2999), iter.next_back());
+    assert_eq!(2998, iter.len());
+
+    let v: Vec<i32> = chunked.clone().into();
+
+    assert_eq!((0..3000).collect::<Vec<i32>>(), v);
+    assert_eq!(SortedChunkedVec::from(v), chunked);
+}
+
+#[test]
+fn gallop_and_hint_match_vec() {
+    let mut chunked = SortedChunkedVec::with_chunk_size(4);
+    let mut v: Vec<u32> = Vec::new();
+
+    let mut rng = XorShift64::new(2463534242);
+    let mut hint = 0;
+
+    for _ in 0..300 {
+        let x = rng.next_u64();
+        let e = (x % 60) as u32;
+
+        assert_eq!(v.sorted_insert_asc_gallop(e), chunked.sorted_insert_asc_gallop(e));
+
+        hint = (hint + (x >> 32) as usize) % (v.len() + 1);
+
+        assert_eq!(
+            v.sorted_insert_asc_with_hint(e, hint),
+            chunked.sorted_insert_asc_with_hint(e, hint)
+        );
+    }
+
+    assert!(chunked.chunk_count() > 50);
+    assert_eq!(v, chunked.into_vec());
+}
+
+#[test]
+fn mut_and_entry_across_chunks() {
+    let mut chunked =
+        SortedChunkedVec::from_vec_with_chunk_size((0..20).map(|e| e * 2).collect(), 4);
+
+    assert_eq!(4, chunked.chunk_size());
+    assert_eq!(10, chunked.chunk_count());
+
+    // the chunks are filled to two elements, so 3 lands at the start of the second chunk and its predecessor is in the first one
+    let inserted = chunked.sorted_insert_asc_mut(3);
+
+    assert_eq!(2, inserted.index());
+    assert_eq!(Some(&2), inserted.predecessor());
+    assert_eq!(Some(&4), inserted.successor());
+
+    assert_eq!(&mut 7, chunked.sorted_entry_asc_by_key(&7, |e| e).or_insert(7));
+    assert_eq!(6, chunked.sorted_entry_asc_by_key(&8, |e| e).index());
+
+    assert_eq!(22, chunked.len());
+    assert!(chunked.iter().zip(chunked.iter().skip(1)).all(|(a, b)| a <= b));
+}
+
+#[test]
+fn merge_and_bulk_keep_chunk_size() {
+    let mut chunked = SortedChunkedVec::from_vec_with_chunk_size(vec![1, 3, 5, 7, 9], 4);
+
+    chunked.sorted_merge_asc(vec![2, 3, 10]);
+
+    assert_eq!(4, chunked.chunk_size());
+    assert_eq!(vec![1, 2, 3, 3, 5, 7, 9, 10], chunked.clone().into_vec());
+
+    let drained = chunked.sorted_drain_range_asc_by_key(3..=5, |e| e);
+
+    assert_eq!(4, drained.chunk_size());
+    assert_eq!(vec![3, 3, 5], drained.into_vec());
+
+    let tail = chunked.sorted_split_off_by(|e| *e < 9);
+
+    assert_eq!(vec![9, 10], tail.into_vec());
+    assert_eq!(vec![1, 2, 7], chunked.into_vec());
+}
+
+#[test]
+fn extend_keeps_chunk_size() {
+    let mut chunked = SortedChunkedVec::from_vec_with_chunk_size(vec![10, 20, 30], 4);
+
+    chunked.extend(vec![25, 5, 20, 35, 15]);
+    chunked.extend(Vec::new());
+
+    assert_eq!(4, chunked.chunk_size());
+    assert_eq!(vec![5, 10, 15, 20, 20, 25, 30, 35], chunked.clone().into_vec());
+
+    for (i, e) in [5, 10, 15, 20, 20, 25, 30, 35].iter().enumerate() {
+        assert_eq!(Some(e), chunked.get(i));
+    }
+
+    assert_eq!(Ok(5), chunked.binary_search(&25));
+}
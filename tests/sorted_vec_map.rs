@@ -0,0 +1,71 @@
+use sorted_insert::{sorted_vec_map::Entry, SortedVecMap};
+
+#[test]
+fn insert_get_remove() {
+    let mut map = SortedVecMap::new();
+
+    assert_eq!(None, map.insert(2, "b"));
+    assert_eq!(None, map.insert(1, "a"));
+    assert_eq!(None, map.insert(3, "c"));
+    assert_eq!(Some("b"), map.insert(2, "B"));
+
+    assert_eq!(3, map.len());
+    assert_eq!([(1, "a"), (2, "B"), (3, "c")], map.as_slice());
+
+    assert_eq!(Some(&"B"), map.get(&2));
+    assert_eq!(None, map.get(&4));
+    assert_eq!("c", map[&3]);
+
+    *map.get_mut(&1).unwrap() = "A";
+    assert_eq!(Some(&"A"), map.get(&1));
+
+    assert_eq!(Some("B"), map.remove(&2));
+    assert_eq!(None, map.remove(&2));
+    assert_eq!([(1, "A"), (3, "c")], map.as_slice());
+}
+
+#[test]
+fn range() {
+    let mut map = SortedVecMap::new();
+
+    for i in (0..10).rev() {
+        map.insert(i, i * 10);
+    }
+
+    assert_eq!(vec![3, 4, 5], map.range(3..6).map(|(k, _)| *k).collect::<Vec<i32>>());
+    assert_eq!(vec![7, 8, 9], map.range(7..).map(|(k, _)| *k).collect::<Vec<i32>>());
+    assert_eq!(vec![0, 1, 2], map.range(..=2).map(|(k, _)| *k).collect::<Vec<i32>>());
+    assert_eq!(vec![90, 80], map.range(8..).rev().map(|(_, v)| *v).collect::<Vec<i32>>());
+    assert_eq!(0, map.range(20..30).count());
+}
+
+#[test]
+fn entry() {
+    let mut map: SortedVecMap<&str, usize> = SortedVecMap::new();
+
+    for word in ["b", "a", "b", "c", "b", "a"] {
+        *map.entry(word).or_insert(0) += 1;
+    }
+
+    assert_eq!([("a", 2), ("b", 3), ("c", 1)], map.as_slice());
+
+    match map.entry("d") {
+        Entry::Occupied(_) => unreachable!(),
+        Entry::Vacant(entry) => {
+            assert_eq!(3, entry.index());
+            assert_eq!(&mut 4, entry.insert(4));
+        },
+    }
+
+    map.entry("a").and_modify(|v| *v = 10).or_default();
+    map.entry("0").and_modify(|v| *v = 10).or_default();
+
+    assert_eq!([("0", 0), ("a", 10), ("b", 3), ("c", 1), ("d", 4)], map.as_slice());
+
+    match map.entry("c") {
+        Entry::Occupied(entry) => assert_eq!(1, entry.remove()),
+        Entry::Vacant(_) => unreachable!(),
+    }
+
+    assert_eq!(vec!["0", "a", "b", "d"], map.keys().copied().collect::<Vec<&str>>());
+}
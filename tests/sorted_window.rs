@@ -0,0 +1,128 @@
+#![cfg(feature = "std")]
+
+use std::time::{Duration, Instant};
+
+use sorted_insert::*;
+
+#[test]
+fn insert_and_evict() {
+    let mut window = SortedWindow::new(10u32, |e: &(u32, char)| &e.0);
+
+    assert_eq!(
+        WindowInsert::Inserted {
+            index: 0, evicted: vec![]
+        },
+        window.insert((5, 'a'))
+    );
+    assert_eq!(
+        WindowInsert::Inserted {
+            index: 1, evicted: vec![]
+        },
+        window.insert((8, 'b'))
+    );
+    assert_eq!(
+        WindowInsert::Inserted {
+            index: 1, evicted: vec![]
+        },
+        window.insert((6, 'c'))
+    );
+
+    // equal keys keep their insertion order
+    assert_eq!(
+        WindowInsert::Inserted {
+            index: 2, evicted: vec![]
+        },
+        window.insert((6, 'd'))
+    );
+
+    // the window reaches back before zero
+    assert_eq!(None, window.start());
+
+    assert_eq!(
+        WindowInsert::Inserted {
+            index: 1, evicted: vec![(5, 'a'), (6, 'c'), (6, 'd')]
+        },
+        window.insert((17, 'e'))
+    );
+    assert_eq!([(8, 'b'), (17, 'e')], Vec::from(window.as_vec_deque().clone()).as_slice());
+    assert_eq!(Some(7), window.start());
+
+    // the oldest key of the window is still accepted
+    assert!(window.insert((7, 'f')).is_inserted());
+    assert_eq!(WindowInsert::TooLate((6, 'g')), window.insert((6, 'g')));
+
+    assert_eq!(Some(&(7, 'f')), window.oldest());
+    assert_eq!(Some(&(17, 'e')), window.newest());
+
+    assert_eq!(vec![(7, 'f'), (8, 'b')], window.advance(&20));
+    assert!(window.advance(&15).is_empty());
+    assert_eq!(1, window.len());
+}
+
+#[test]
+fn key_underflow() {
+    let mut window = SortedWindow::new(100u8, |e: &u8| e);
+
+    for e in [50, 3, 99, 0] {
+        assert!(window.insert(e).is_inserted());
+    }
+
+    assert_eq!(None, window.start());
+    assert_eq!(vec![&0, &3, &50, &99], window.iter().collect::<Vec<_>>());
+
+    assert_eq!(
+        WindowInsert::Inserted {
+            index: 2, evicted: vec![0, 3]
+        },
+        window.insert(104)
+    );
+}
+
+#[test]
+fn instant_keys() {
+    let now = Instant::now();
+    let mut window = SortedWindow::new(Duration::from_secs(60), |e: &(Instant, u32)| &e.0);
+
+    window.insert((now, 1));
+    window.insert((now + Duration::from_secs(30), 2));
+
+    let evicted = window.advance(&(now + Duration::from_secs(61)));
+
+    assert_eq!(vec![(now, 1)], evicted);
+    assert_eq!(1, window.len());
+}
+
+#[test]
+fn late_after_advance() {
+    let mut window = SortedWindow::new(10u32, |e: &u32| e);
+
+    assert!(window.insert(195).is_inserted());
+    assert_eq!(vec![195], window.advance(&210));
+
+    // the window is empty, but it still starts at 200
+    assert!(window.is_empty());
+    assert_eq!(Some(&210), window.latest());
+    assert_eq!(Some(200), window.start());
+    assert_eq!(WindowInsert::TooLate(185), window.insert(185));
+    assert_eq!(WindowInsert::TooLate(199), window.insert(199));
+
+    // an element older than the latest key, but within the window, does not move it back
+    assert_eq!(
+        WindowInsert::Inserted {
+            index: 0, evicted: vec![]
+        },
+        window.insert(205)
+    );
+    assert_eq!(Some(&210), window.latest());
+
+    let mut window = SortedWindow::new(10u32, |e: &u32| e);
+
+    assert!(window.insert(195).is_inserted());
+    assert!(window.advance(&200).is_empty());
+    assert_eq!(WindowInsert::TooLate(185), window.insert(185));
+
+    window.clear();
+
+    assert_eq!(WindowInsert::TooLate(189), window.insert(189));
+    assert!(window.insert(190).is_inserted());
+}
@@ -0,0 +1,125 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use common::XorShift64;
+use sorted_insert::*;
+
+#[test]
+fn fifo_ties() {
+    let mut scheduler = StableScheduler::new();
+
+    for (i, deadline) in [3, 1, 3, 2, 1, 3].into_iter().enumerate() {
+        scheduler.schedule(deadline, i);
+    }
+
+    assert_eq!(Some((&1, &1)), scheduler.peek());
+    assert_eq!(
+        vec![(&1, &1), (&1, &4), (&2, &3), (&3, &0), (&3, &2), (&3, &5)],
+        scheduler.iter().collect::<Vec<_>>()
+    );
+
+    let mut popped = Vec::new();
+
+    while let Some((_, job)) = scheduler.pop_due(&2) {
+        popped.push(job);
+    }
+
+    assert_eq!([1, 4, 3], popped.as_slice());
+    assert_eq!(3, scheduler.len());
+}
+
+#[test]
+fn cancel_and_reschedule() {
+    let mut scheduler = StableScheduler::new();
+
+    let a = scheduler.schedule(10, 'a');
+    let b = scheduler.schedule(10, 'b');
+    let c = scheduler.schedule(5, 'c');
+
+    assert_eq!(Some(&'a'), scheduler.get(a));
+    assert_eq!(Some(&5), scheduler.deadline(c));
+
+    // rescheduling at the same deadline moves the job behind its ties
+    assert_eq!(Some(10), scheduler.reschedule(a, 10));
+    assert_eq!(vec![&'c', &'b', &'a'], scheduler.iter().map(|(_, job)| job).collect::<Vec<_>>());
+
+    *scheduler.get_mut(b).unwrap() = 'B';
+
+    assert_eq!(Some((5, 'c')), scheduler.cancel(c));
+    assert_eq!(None, scheduler.cancel(c));
+    assert_eq!(None, scheduler.reschedule(c, 1));
+    assert!(!scheduler.contains(c));
+
+    assert_eq!(Some(10), scheduler.reschedule(b, 20));
+    assert_eq!(Some((10, 'a')), scheduler.pop_due(&15));
+    assert_eq!(None, scheduler.pop_due(&15));
+    assert!(!scheduler.contains(a));
+    assert_eq!(None, scheduler.get(a));
+    assert_eq!(Some((20, 'B')), scheduler.pop_due(&20));
+    assert!(scheduler.is_empty());
+}
+
+#[test]
+fn matches_model() {
+    let mut rng = XorShift64::new(7);
+    let mut random = move |modulo: u64| rng.next_below(modulo);
+
+    let mut scheduler = StableScheduler::new();
+    // (deadline, seq, job, handle), kept sorted by deadline and then seq
+    let mut model: Vec<(u64, u64, u64, ScheduleHandle)> = Vec::new();
+    let mut handles = Vec::new();
+    let mut seq = 0;
+    let mut now = 0;
+
+    for job in 0..5000 {
+        seq += 1;
+
+        match random(5) {
+            0 | 1 => {
+                let deadline = now + random(20);
+                let handle = scheduler.schedule(deadline, job);
+
+                model.push((deadline, seq, job, handle));
+                handles.push(handle);
+            },
+            2 if !handles.is_empty() => {
+                let handle = handles[random(handles.len() as u64) as usize];
+                let expected = model.iter().position(|e| e.3 == handle).map(|i| model.remove(i));
+
+                assert_eq!(expected.map(|e| (e.0, e.2)), scheduler.cancel(handle));
+            },
+            3 if !handles.is_empty() => {
+                let handle = handles[random(handles.len() as u64) as usize];
+                let deadline = now + random(20);
+
+                let expected = model.iter_mut().find(|e| e.3 == handle).map(|e| {
+                    let old = e.0;
+
+                    e.0 = deadline;
+                    e.1 = seq;
+
+                    old
+                });
+
+                assert_eq!(expected, scheduler.reschedule(handle, deadline));
+            },
+            _ => {
+                now += random(3);
+
+                model.sort_by_key(|e| (e.0, e.1));
+
+                while let Some((deadline, job)) = scheduler.pop_due(&now) {
+                    let expected = model.remove(0);
+
+                    assert_eq!((expected.0, expected.2), (deadline, job));
+                }
+            },
+        }
+
+        model.sort_by_key(|e| (e.0, e.1));
+
+        assert_eq!(model.len(), scheduler.len());
+        assert!(model.iter().map(|e| (&e.0, &e.2)).eq(scheduler.iter()));
+    }
+}
@@ -0,0 +1,255 @@
+#![cfg(feature = "testing")]
+
+use std::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, RwLock},
+};
+
+use sorted_insert::{testing::*, *};
+
+#[test]
+fn check_operations_vec() {
+    let mut v: Vec<u32> = vec![9, 5, 5, 1];
+
+    check_operations(&mut v, Order::Descending, &mut [&mut Linear, &mut Binary], [
+        Operation::new(0, 5),
+        Operation::new(1, 5),
+        Operation::new(0, 10),
+        Operation::new(1, 0),
+    ])
+    .unwrap();
+
+    assert_eq!([10, 9, 5, 5, 5, 5, 1, 0], v.as_slice());
+}
+
+#[test]
+fn check_random_collections() {
+    for order in [Order::Ascending, Order::Descending] {
+        for seed in 0..10 {
+            let mut v: Vec<u64> = Vec::new();
+
+            check_random(
+                &mut v,
+                order,
+                &mut [
+                    &mut Linear,
+                    &mut Binary,
+                    &mut Gallop,
+                    &mut WithHint::default(),
+                    &mut BinaryByComparator,
+                ],
+                500,
+                seed,
+                |rng| rng.next_below(20),
+            )
+            .unwrap();
+
+            #[cfg(feature = "std")]
+            {
+                let mut v: VecDeque<u64> = VecDeque::new();
+
+                check_random(
+                    &mut v,
+                    order,
+                    &mut [&mut Linear, &mut Binary, &mut Gallop, &mut WithHint::default()],
+                    500,
+                    seed,
+                    |rng| rng.next_below(20),
+                )
+                .unwrap();
+            }
+
+            let mut v: SortedChunkedVec<u64> = SortedChunkedVec::with_chunk_size(4);
+
+            check_random(
+                &mut v,
+                order,
+                &mut [&mut Linear, &mut Binary, &mut Gallop, &mut WithHint::default()],
+                500,
+                seed,
+                |rng| rng.next_below(20),
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// An element which is not `Ord`, so only the `*By` and `*ByKey` traits can insert it.
+#[derive(Debug, Clone)]
+struct Sample {
+    time:  u32,
+    value: f64,
+}
+
+#[test]
+fn check_by_key_only() {
+    let mut v: Vec<Sample> = Vec::new();
+
+    let comparator = Natural::new().by_key(|sample: &Sample| &sample.time);
+
+    let mut by_key = InsertWith::new(Placement::Last, |v: &mut Vec<Sample>, sample| {
+        v.sorted_insert_asc_by_key(sample, |sample| &sample.time)
+    });
+
+    check_random(&mut v, comparator, &mut [&mut Linear, &mut Binary, &mut by_key], 300, 3, |rng| {
+        Sample {
+            time: rng.next_below(10) as u32, value: rng.next_below(100) as f64
+        }
+    })
+    .unwrap();
+
+    assert_eq!(300, v.len());
+    assert!(v.windows(2).all(|w| w[0].time <= w[1].time && w[0].value >= 0.0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_lock_collections() {
+    // the model and the collection share the same `Arc`s, which must not be locked twice
+    let mutex_comparator = |a: &Arc<Mutex<u32>>, b: &Arc<Mutex<u32>>| {
+        if Arc::ptr_eq(a, b) {
+            Ordering::Equal
+        } else {
+            a.lock().unwrap().cmp(&b.lock().unwrap())
+        }
+    };
+
+    let mut v: Vec<Arc<Mutex<u32>>> = Vec::new();
+
+    check_random(
+        &mut v,
+        mutex_comparator,
+        &mut [
+            &mut InsertWith::new(Placement::Last, |v: &mut Vec<Arc<Mutex<u32>>>, e| {
+                SortedInsertArcMutex::sorted_insert_asc(v, e)
+            }),
+            &mut InsertWith::new(Placement::Any, |v: &mut Vec<Arc<Mutex<u32>>>, e| {
+                SortedInsertBinaryArcMutex::sorted_insert_asc_binary(v, e)
+            }),
+            &mut InsertWith::new(Placement::Last, |v: &mut Vec<Arc<Mutex<u32>>>, e| {
+                SortedInsertGallopArcMutex::sorted_insert_asc_gallop(v, e)
+            }),
+        ],
+        300,
+        5,
+        |rng| Arc::new(Mutex::new(rng.next_below(10) as u32)),
+    )
+    .unwrap();
+
+    let mut v: VecDeque<Arc<RwLock<u32>>> = VecDeque::new();
+
+    check_random(
+        &mut v,
+        |a: &Arc<RwLock<u32>>, b: &Arc<RwLock<u32>>| b.read().unwrap().cmp(&a.read().unwrap()),
+        &mut [
+            &mut InsertWith::new(Placement::Last, |v: &mut VecDeque<Arc<RwLock<u32>>>, e| {
+                SortedInsertArcRwLock::sorted_insert_desc(v, e)
+            }),
+            &mut InsertWith::new(Placement::Any, |v: &mut VecDeque<Arc<RwLock<u32>>>, e| {
+                SortedInsertBinaryArcRwLock::sorted_insert_desc_binary(v, e)
+            }),
+        ],
+        300,
+        5,
+        |rng| Arc::new(RwLock::new(rng.next_below(10) as u32)),
+    )
+    .unwrap();
+}
+
+#[test]
+fn xorshift_is_reproducible() {
+    let mut a = XorShift64::new(0);
+    let mut b = XorShift64::new(0);
+
+    for _ in 0..100 {
+        let x = a.next_below(10);
+
+        assert_eq!(x, b.next_below(10));
+        assert!(x < 10);
+    }
+}
+
+// `debug-checks` panics on the broken insertion before the checker sees it
+#[cfg(not(feature = "debug-checks"))]
+mod off_by_one {
+    use super::*;
+
+    /// A collection whose binary insertion puts elements one index too far when they are not the last. It only implements the linear and binary `*By` traits.
+    #[derive(Default)]
+    struct OffByOne(Vec<u32>);
+
+    impl SortedInsertBasic<u32> for OffByOne {
+        fn insert_element(&mut self, index: usize, element: u32) {
+            self.0.insert(index, element)
+        }
+
+        fn get_element(&self, index: usize) -> Option<&u32> {
+            self.0.get(index)
+        }
+    }
+
+    impl SortedInsertBy<u32> for OffByOne {
+        fn get_sorted_insert_index_by<F: FnMut(&u32) -> bool>(&self, f: F) -> usize {
+            self.0.get_sorted_insert_index_by(f)
+        }
+    }
+
+    impl SortedInsertBinaryBy<u32> for OffByOne {
+        fn get_sorted_insert_index_binary_by<F: FnMut(&u32) -> Ordering>(&mut self, f: F) -> usize {
+            let index = self.0.get_sorted_insert_index_binary_by(f);
+
+            if index < self.0.len() {
+                index + 1
+            } else {
+                index
+            }
+        }
+    }
+
+    #[test]
+    fn divergence_is_reported() {
+        let mut c = OffByOne::default();
+
+        let divergence =
+            check_operations(&mut c, Order::Ascending, &mut [&mut Linear, &mut Binary], [
+                Operation::new(0, 2),
+                Operation::new(1, 3),
+                Operation::new(1, 1),
+            ])
+            .unwrap_err();
+
+        assert_eq!(
+            Divergence::Index {
+                step:      2,
+                operation: Operation::new(1, 1),
+                index:     1,
+                start:     0,
+                end:       0,
+            },
+            divergence
+        );
+        assert_eq!(2, divergence.step());
+        assert_eq!(
+            "step 2 (inserting 1 with strategy 1) returned the index 1, but the element can only \
+             be inserted at 0..=0",
+            divergence.to_string()
+        );
+
+        let mut c = OffByOne(vec![1, 2, 3]);
+
+        check_random(&mut c, Order::Ascending, &mut [&mut Linear], 100, 1, |rng| {
+            rng.next_below(4) as u32
+        })
+        .unwrap();
+
+        let divergence =
+            check_random(&mut c, Order::Ascending, &mut [&mut Binary], 100, 1, |rng| {
+                rng.next_below(4) as u32
+            })
+            .unwrap_err();
+
+        assert_eq!(0, divergence.operation().strategy);
+    }
+}
@@ -14,6 +14,11 @@ fn basic() {
 
     let mut v: Vec<isize> = Vec::new();
 
+    assert_eq!(0, v.sorted_insert_asc_gallop(1));
+    assert_eq!([1], v.as_slice());
+
+    let mut v: Vec<isize> = Vec::new();
+
     assert_eq!(0, v.sorted_insert_desc(1));
     assert_eq!([1], v.as_slice());
 
@@ -21,6 +26,11 @@ fn basic() {
 
     assert_eq!(0, v.sorted_insert_desc_binary(1));
     assert_eq!([1], v.as_slice());
+
+    let mut v: Vec<isize> = Vec::new();
+
+    assert_eq!(0, v.sorted_insert_desc_gallop(1));
+    assert_eq!([1], v.as_slice());
 }
 
 #[test]
@@ -37,6 +47,11 @@ fn init_one_element() {
 
     let mut v: Vec<isize> = vec![0];
 
+    assert_eq!(1, v.sorted_insert_asc_gallop(1));
+    assert_eq!([0, 1], v.as_slice());
+
+    let mut v: Vec<isize> = vec![0];
+
     assert_eq!(0, v.sorted_insert_desc(1));
     assert_eq!([1, 0], v.as_slice());
 
@@ -45,6 +60,11 @@ fn init_one_element() {
     assert_eq!(0, v.sorted_insert_desc_binary(1));
     assert_eq!([1, 0], v.as_slice());
 
+    let mut v: Vec<isize> = vec![0];
+
+    assert_eq!(0, v.sorted_insert_desc_gallop(1));
+    assert_eq!([1, 0], v.as_slice());
+
     // ----------
 
     let mut v: Vec<isize> = vec![2];
@@ -59,6 +79,11 @@ fn init_one_element() {
 
     let mut v: Vec<isize> = vec![2];
 
+    assert_eq!(0, v.sorted_insert_asc_gallop(1));
+    assert_eq!([1, 2], v.as_slice());
+
+    let mut v: Vec<isize> = vec![2];
+
     assert_eq!(1, v.sorted_insert_desc(1));
     assert_eq!([2, 1], v.as_slice());
 
@@ -66,6 +91,11 @@ fn init_one_element() {
 
     assert_eq!(1, v.sorted_insert_desc_binary(1));
     assert_eq!([2, 1], v.as_slice());
+
+    let mut v: Vec<isize> = vec![2];
+
+    assert_eq!(1, v.sorted_insert_desc_gallop(1));
+    assert_eq!([2, 1], v.as_slice());
 }
 
 #[test]
@@ -80,6 +110,11 @@ fn init_two_elements() {
     assert_eq!(1, v.sorted_insert_asc_binary(1));
     assert_eq!([0, 1, 2], v.as_slice());
 
+    let mut v: Vec<isize> = vec![0, 2];
+
+    assert_eq!(1, v.sorted_insert_asc_gallop(1));
+    assert_eq!([0, 1, 2], v.as_slice());
+
     let mut v: Vec<isize> = vec![2, 0];
 
     assert_eq!(1, v.sorted_insert_desc(1));
@@ -89,6 +124,11 @@ fn init_two_elements() {
 
     assert_eq!(1, v.sorted_insert_desc_binary(1));
     assert_eq!([2, 1, 0], v.as_slice());
+
+    let mut v: Vec<isize> = vec![2, 0];
+
+    assert_eq!(1, v.sorted_insert_desc_gallop(1));
+    assert_eq!([2, 1, 0], v.as_slice());
 }
 
 #[test]
@@ -103,6 +143,11 @@ fn init_three_elements() {
     assert_eq!(2, v.sorted_insert_asc_binary(1));
     assert_eq!([0, 1, 1, 2], v.as_slice());
 
+    let mut v: Vec<isize> = vec![0, 1, 2];
+
+    assert_eq!(2, v.sorted_insert_asc_gallop(1));
+    assert_eq!([0, 1, 1, 2], v.as_slice());
+
     let mut v: Vec<isize> = vec![2, 1, 0];
 
     assert_eq!(2, v.sorted_insert_desc(1));
@@ -112,4 +157,24 @@ fn init_three_elements() {
 
     assert_eq!(2, v.sorted_insert_desc_binary(1));
     assert_eq!([2, 1, 1, 0], v.as_slice());
+
+    let mut v: Vec<isize> = vec![2, 1, 0];
+
+    assert_eq!(2, v.sorted_insert_desc_gallop(1));
+    assert_eq!([2, 1, 1, 0], v.as_slice());
+}
+
+#[test]
+fn gallop_far_from_end() {
+    let init: Vec<isize> = (0..100).map(|i| i / 3).collect();
+
+    for element in -1..=34 {
+        let mut expected = init.clone();
+        let expected_index = expected.sorted_insert_asc(element);
+
+        let mut v = init.clone();
+
+        assert_eq!(expected_index, v.sorted_insert_asc_gallop(element));
+        assert_eq!(expected, v);
+    }
 }